@@ -4,7 +4,7 @@ use crate::{PathItemDefinition, ResponseWrapper};
 #[cfg(feature = "actix")]
 use actix_web::Either;
 use apistos_models::Schema;
-use apistos_models::paths::{MediaType, Parameter, RequestBody, Response, Responses};
+use apistos_models::paths::{Example, Examples, MediaType, Parameter, RequestBody, Response, Responses};
 use apistos_models::reference_or::ReferenceOr;
 use apistos_models::security::SecurityScheme;
 #[cfg(feature = "actix")]
@@ -33,6 +33,30 @@ pub trait ApiComponent {
 
   fn schema() -> Option<(String, ReferenceOr<Schema>)>;
 
+  /// Named examples of this component, registered in `components.examples`
+  fn examples() -> Vec<(String, Example)> {
+    vec![]
+  }
+
+  /// References to this component's examples, to be used in media types
+  fn media_type_examples() -> Option<Examples> {
+    let examples = Self::examples();
+    if examples.is_empty() {
+      return None;
+    }
+    Some(Examples::Examples(
+      examples
+        .into_iter()
+        .map(|(name, _)| {
+          let reference = ReferenceOr::Reference {
+            _ref: format!("#/components/examples/{name}"),
+          };
+          (name, reference)
+        })
+        .collect(),
+    ))
+  }
+
   fn securities() -> BTreeMap<String, SecurityScheme> {
     Default::default()
   }
@@ -49,6 +73,7 @@ pub trait ApiComponent {
           schema: Some(ReferenceOr::Reference {
             _ref: format!("#/components/schemas/{name}"),
           }),
+          example: Self::media_type_examples(),
           ..Default::default()
         },
       )]),
@@ -57,6 +82,22 @@ pub trait ApiComponent {
     })
   }
 
+  /// Schema documenting this component as a response body, referencing `components.schemas` for named schemas except arrays which are inlined
+  fn response_schema() -> Option<ReferenceOr<Schema>> {
+    match Self::schema() {
+      Some((_, schema @ ReferenceOr::Reference { .. })) => Some(schema),
+      Some((_, ReferenceOr::Object(Schema::Object(obj))))
+        if obj.instance_type == Some(SingleOrVec::Single(Box::new(InstanceType::Array))) =>
+      {
+        Some(ReferenceOr::Object(Schema::Object(obj)))
+      }
+      Some((name, ReferenceOr::Object(_))) => Some(ReferenceOr::Reference {
+        _ref: format!("#/components/schemas/{name}"),
+      }),
+      None => Self::raw_schema(),
+    }
+  }
+
   fn error_responses() -> Vec<(String, Response)> {
     vec![]
   }
@@ -65,6 +106,14 @@ pub trait ApiComponent {
     BTreeMap::default()
   }
 
+  fn error_child_schemas() -> Vec<(String, ReferenceOr<Schema>)> {
+    vec![]
+  }
+
+  fn error_response_name(_status_code: &str) -> Option<String> {
+    None
+  }
+
   fn responses(_content_type: Option<String>) -> Option<Responses> {
     None
   }
@@ -94,6 +143,10 @@ where
     T::schema()
   }
 
+  fn examples() -> Vec<(String, Example)> {
+    T::examples()
+  }
+
   fn securities() -> BTreeMap<String, SecurityScheme> {
     T::securities()
   }
@@ -164,6 +217,10 @@ where
     T::schema()
   }
 
+  fn examples() -> Vec<(String, Example)> {
+    T::examples()
+  }
+
   // We expect error to be present only for response part
   fn error_responses() -> Vec<(String, Response)> {
     E::error_responses()
@@ -174,6 +231,16 @@ where
     E::schemas_by_status_code()
   }
 
+  // We expect error to be present only for response part
+  fn error_child_schemas() -> Vec<(String, ReferenceOr<Schema>)> {
+    E::error_child_schemas()
+  }
+
+  // We expect error to be present only for response part
+  fn error_response_name(status_code: &str) -> Option<String> {
+    E::error_response_name(status_code)
+  }
+
   fn responses(content_type: Option<String>) -> Option<Responses> {
     T::responses(content_type)
   }
@@ -192,9 +259,42 @@ where
   fn child_schemas() -> Vec<(String, ReferenceOr<Schema>)> {
     let mut child_schemas = T::child_schemas();
     child_schemas.append(&mut E::child_schemas());
+    // bodies of different content types are referenced separately, see `request_body`
+    if T::content_type() != E::content_type() {
+      child_schemas.extend(T::schema());
+      child_schemas.extend(E::schema());
+    }
     child_schemas
   }
 
+  /// Bodies of different content types (e.g. `Either<Json<A>, Form<B>>`) are documented as separate media types, bodies sharing a content type as `oneOf` both schemas.
+  fn request_body() -> Option<RequestBody> {
+    if T::content_type() == E::content_type() {
+      return Self::schema().map(|(name, _)| RequestBody {
+        content: BTreeMap::from_iter(vec![(
+          T::content_type(),
+          MediaType {
+            schema: Some(ReferenceOr::Reference {
+              _ref: format!("#/components/schemas/{name}"),
+            }),
+            example: Self::media_type_examples(),
+            ..Default::default()
+          },
+        )]),
+        required: Some(Self::required()),
+        ..Default::default()
+      });
+    }
+    match (T::request_body(), E::request_body()) {
+      (Some(mut request_body), Some(mut other_request_body)) => {
+        request_body.content.append(&mut other_request_body.content);
+        request_body.required = Some(Self::required());
+        Some(request_body)
+      }
+      (request_body, other_request_body) => request_body.or(other_request_body),
+    }
+  }
+
   fn raw_schema() -> Option<ReferenceOr<Schema>> {
     match (T::raw_schema(), E::raw_schema()) {
       (Some(raw_schema1), Some(raw_schema2)) => {
@@ -226,8 +326,10 @@ where
     }
   }
 
+  /// Bodies of different content types have no combined schema, both schemas being part of `child_schemas` instead.
   fn schema() -> Option<(String, ReferenceOr<Schema>)> {
     match (T::schema(), E::schema()) {
+      (Some(_), Some(_)) if T::content_type() != E::content_type() => None,
       (Some(schema1), Some(schema2)) => {
         let (schema_name1, schema1) = schema1;
         let schema1 = match schema1 {
@@ -261,6 +363,12 @@ where
     }
   }
 
+  fn examples() -> Vec<(String, Example)> {
+    let mut examples = T::examples();
+    examples.append(&mut E::examples());
+    examples
+  }
+
   fn error_responses() -> Vec<(String, Response)> {
     let mut error_responses = T::error_responses();
     error_responses.append(&mut E::error_responses());
@@ -273,6 +381,17 @@ where
     error_schemas
   }
 
+  fn error_child_schemas() -> Vec<(String, ReferenceOr<Schema>)> {
+    let mut error_child_schemas = T::error_child_schemas();
+    error_child_schemas.append(&mut E::error_child_schemas());
+    error_child_schemas
+  }
+
+  // error responses of E take precedence over the ones of T, see `error_responses`
+  fn error_response_name(status_code: &str) -> Option<String> {
+    E::error_response_name(status_code).or_else(|| T::error_response_name(status_code))
+  }
+
   fn responses(content_type: Option<String>) -> Option<Responses> {
     let responses = T::responses(content_type.clone());
     match responses {
@@ -328,6 +447,14 @@ where
     R::schema()
   }
 
+  fn response_schema() -> Option<ReferenceOr<Schema>> {
+    R::response_schema()
+  }
+
+  fn examples() -> Vec<(String, Example)> {
+    R::examples()
+  }
+
   fn error_responses() -> Vec<(String, Response)> {
     R::error_responses()
   }
@@ -336,6 +463,14 @@ where
     R::error_schemas()
   }
 
+  fn error_child_schemas() -> Vec<(String, ReferenceOr<Schema>)> {
+    R::error_child_schemas()
+  }
+
+  fn error_response_name(status_code: &str) -> Option<String> {
+    R::error_response_name(status_code)
+  }
+
   fn responses(content_type: Option<String>) -> Option<Responses> {
     let mut responses = vec![];
     if let Some(response) = R::responses(content_type.clone()) {
@@ -345,39 +480,7 @@ where
           .into_iter()
           .collect::<Vec<(String, ReferenceOr<Response>)>>(),
       );
-    } else if let Some((name, schema)) = Self::schema() {
-      let ref_or = match schema {
-        r @ ReferenceOr::Reference { .. } => r,
-        ReferenceOr::Object(schema_obj) => {
-          let _ref = ReferenceOr::Reference {
-            _ref: format!("#/components/schemas/{name}"),
-          };
-          match schema_obj {
-            Schema::Object(obj) => {
-              if obj.instance_type == Some(SingleOrVec::Single(Box::new(InstanceType::Array))) {
-                ReferenceOr::Object(Schema::Object(obj))
-              } else {
-                _ref
-              }
-            }
-            Schema::Bool(_) => _ref,
-          }
-        }
-      };
-      responses.push((
-        "200".to_owned(),
-        ReferenceOr::Object(Response {
-          content: BTreeMap::from_iter(vec![(
-            content_type.unwrap_or_else(Self::content_type),
-            MediaType {
-              schema: Some(ref_or),
-              ..Default::default()
-            },
-          )]),
-          ..Default::default()
-        }),
-      ));
-    } else if let Some(schema) = Self::raw_schema() {
+    } else if let Some(schema) = Self::response_schema() {
       responses.push((
         "200".to_owned(),
         ReferenceOr::Object(Response {
@@ -385,6 +488,7 @@ where
             content_type.unwrap_or_else(Self::content_type),
             MediaType {
               schema: Some(schema),
+              example: Self::media_type_examples(),
               ..Default::default()
             },
           )]),
@@ -403,10 +507,19 @@ where
       responses.push(("200".to_owned(), ReferenceOr::Object(Response::default())));
     }
 
+    // shared error responses are registered in `components.responses`
     responses.append(
       &mut Self::error_responses()
         .into_iter()
-        .map(|(status, schema)| (status, ReferenceOr::Object(schema)))
+        .map(|(status, response)| match Self::error_response_name(&status) {
+          Some(name) => (
+            status,
+            ReferenceOr::Reference {
+              _ref: format!("#/components/responses/{name}"),
+            },
+          ),
+          None => (status, ReferenceOr::Object(response)),
+        })
         .collect(),
     );
     Some(Responses {
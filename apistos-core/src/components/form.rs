@@ -1,6 +1,7 @@
 use crate::ApiComponent;
 use actix_web::web::Form;
 use apistos_models::Schema;
+use apistos_models::paths::Example;
 use apistos_models::reference_or::ReferenceOr;
 
 impl<T> ApiComponent for Form<T>
@@ -18,6 +19,10 @@ where
   fn schema() -> Option<(String, ReferenceOr<Schema>)> {
     T::schema()
   }
+
+  fn examples() -> Vec<(String, Example)> {
+    T::examples()
+  }
 }
 
 #[cfg(feature = "garde")]
@@ -36,4 +41,8 @@ where
   fn schema() -> Option<(String, ReferenceOr<Schema>)> {
     T::schema()
   }
+
+  fn examples() -> Vec<(String, Example)> {
+    T::examples()
+  }
 }
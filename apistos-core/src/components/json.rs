@@ -1,6 +1,7 @@
 use crate::ApiComponent;
 use actix_web::web::Json;
 use apistos_models::Schema;
+use apistos_models::paths::Example;
 use apistos_models::reference_or::ReferenceOr;
 
 impl<T> ApiComponent for Json<T>
@@ -22,6 +23,10 @@ where
   fn schema() -> Option<(String, ReferenceOr<Schema>)> {
     T::schema()
   }
+
+  fn examples() -> Vec<(String, Example)> {
+    T::examples()
+  }
 }
 
 #[cfg(feature = "garde")]
@@ -44,4 +49,8 @@ where
   fn schema() -> Option<(String, ReferenceOr<Schema>)> {
     T::schema()
   }
+
+  fn examples() -> Vec<(String, Example)> {
+    T::examples()
+  }
 }
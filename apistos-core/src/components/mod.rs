@@ -11,3 +11,5 @@ pub mod parameters;
 #[cfg(feature = "actix-session")]
 pub mod session;
 pub mod simple;
+#[cfg(feature = "lab_sse")]
+pub mod sse;
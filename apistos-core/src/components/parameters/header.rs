@@ -1,8 +1,10 @@
 use crate::ApiComponent;
+use crate::components::parameters::first_example_value;
 use actix_web::web::Header;
 use apistos_models::Schema;
-use apistos_models::paths::{Parameter, ParameterDefinition, ParameterIn, ParameterStyle, RequestBody};
+use apistos_models::paths::{Examples, Parameter, ParameterDefinition, ParameterIn, ParameterStyle, RequestBody};
 use apistos_models::reference_or::ReferenceOr;
+use schemars::_serde_json::Value;
 
 pub trait ApiHeader {
   fn name() -> String;
@@ -15,6 +17,9 @@ pub trait ApiHeader {
   fn deprecated() -> bool {
     Default::default()
   }
+  fn extensions() -> Vec<(String, Value)> {
+    vec![]
+  }
 }
 
 impl<T> ApiComponent for Header<T>
@@ -50,6 +55,8 @@ where
         .or_else(Self::raw_schema)
         .map(Box::new)
         .map(ParameterDefinition::Schema),
+      example: first_example_value::<T>().map(Examples::Example),
+      extensions: T::extensions().into_iter().collect(),
       ..Default::default()
     }]
   }
@@ -89,6 +96,8 @@ where
         .or_else(Self::raw_schema)
         .map(Box::new)
         .map(ParameterDefinition::Schema),
+      example: first_example_value::<T>().map(Examples::Example),
+      extensions: T::extensions().into_iter().collect(),
       ..Default::default()
     }]
   }
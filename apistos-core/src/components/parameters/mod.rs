@@ -1,3 +1,27 @@
+use crate::ApiComponent;
+use apistos_models::paths::{ExampleValue, Examples, Parameter};
+use schemars::_serde_json::Value;
+
 pub mod header;
 mod path;
 mod query;
+
+/// First example of the component with an inlined value
+fn first_example_value<T: ApiComponent>() -> Option<Value> {
+  T::examples().into_iter().find_map(|(_, example)| match example.value {
+    ExampleValue::Value(value) => Some(value),
+    ExampleValue::ExternalValue(_) => None,
+  })
+}
+
+/// Document each parameter with the matching property of the component's first example
+fn with_property_examples<T: ApiComponent>(mut parameters: Vec<Parameter>) -> Vec<Parameter> {
+  if let Some(Value::Object(example)) = first_example_value::<T>() {
+    for parameter in &mut parameters {
+      if let Some(value) = example.get(&parameter.name) {
+        parameter.example = Some(Examples::Example(value.clone()));
+      }
+    }
+  }
+  parameters
+}
@@ -1,4 +1,5 @@
 use crate::ApiComponent;
+use crate::components::parameters::with_property_examples;
 use actix_web::web::Path;
 use apistos_models::ObjectValidation;
 use apistos_models::Schema;
@@ -35,7 +36,7 @@ where
     let schema = T::schema().map(|(_, sch)| sch).or_else(Self::raw_schema);
 
     if let Some(schema) = schema {
-      parameters_for_schema(schema, Self::required())
+      with_property_examples::<T>(parameters_for_schema(schema, Self::required()))
     } else {
       vec![]
     }
@@ -71,7 +72,7 @@ where
   fn parameters() -> Vec<Parameter> {
     let schema = T::schema().map(|(_, sch)| sch).or_else(Self::raw_schema);
     if let Some(schema) = schema {
-      parameters_for_schema(schema, Self::required())
+      with_property_examples::<T>(parameters_for_schema(schema, Self::required()))
     } else {
       vec![]
     }
@@ -110,7 +111,7 @@ macro_rules! impl_path_tuple ({ $($ty:ident),+ } => {
         let schema = $ty::schema().map(|(_, sch)| sch).or_else($ty::raw_schema);
 
         if let Some(schema) = schema {
-          parameters.append(&mut parameters_for_schema(schema, Self::required()));
+          parameters.append(&mut with_property_examples::<$ty>(parameters_for_schema(schema, Self::required())));
         }
       )+
       parameters
@@ -149,7 +150,7 @@ macro_rules! impl_path_tuple ({ $($ty:ident),+ } => {
         let schema = $ty::schema().map(|(_, sch)| sch).or_else($ty::raw_schema);
 
         if let Some(schema) = schema {
-          parameters.append(&mut parameters_for_schema(schema, Self::required()));
+          parameters.append(&mut with_property_examples::<$ty>(parameters_for_schema(schema, Self::required())));
         }
       )+
       parameters
@@ -1,4 +1,5 @@
 use crate::ApiComponent;
+use crate::components::parameters::with_property_examples;
 #[cfg(feature = "query")]
 use actix_web::web::Query;
 #[cfg(feature = "lab_query")]
@@ -56,7 +57,7 @@ macro_rules! impl_query {
 
       fn parameters() -> Vec<Parameter> {
         let schema = T::schema().map(|(_, sch)| sch).or_else(Self::raw_schema);
-        parameters_from_schema(schema, None, &None, &$style, $explode)
+        with_property_examples::<T>(parameters_from_schema(schema, None, &None, &$style, $explode))
       }
     }
 
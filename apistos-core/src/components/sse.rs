@@ -0,0 +1,123 @@
+use crate::ApiComponent;
+use actix_web::body::BoxBody;
+use actix_web::{HttpRequest, HttpResponse, Responder};
+use actix_web_lab::sse::Sse;
+use apistos_models::Schema;
+use apistos_models::paths::{Example, MediaType, Response, Responses};
+use apistos_models::reference_or::ReferenceOr;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::marker::PhantomData;
+
+/// Payload of server-sent events, documented as the schema of the `text/event-stream` responses.
+///
+/// Usually derived through `#[derive(ApiSseEvent)]`, event names being declared with `#[openapi_sse_event(event = "...")]`.
+pub trait ApiSseEvent: ApiComponent {
+  /// Names of the events (`event:` field) sent with this payload, documented through the `x-event-names` extension of the media type
+  fn event_names() -> Vec<String> {
+    vec![]
+  }
+}
+
+/// Wrapper of [`actix_web_lab::sse::Sse`](https://docs.rs/actix-web-lab/*/actix_web_lab/sse/struct.Sse.html) documenting a `text/event-stream` response whose events carry **`T`** payloads.
+///
+/// ```rust,ignore
+/// #[api_operation(summary = "Stream pet updates")]
+/// async fn pet_updates() -> TypedSse<PetUpdate, impl Stream<Item = Result<Event, Infallible>>> {
+///   Sse::from_stream(updates()).into()
+/// }
+/// ```
+pub struct TypedSse<T, S> {
+  sse: Sse<S>,
+  _payload: PhantomData<T>,
+}
+
+impl<T, S> TypedSse<T, S> {
+  /// Document **`sse`** as a stream of **`T`** events.
+  pub fn new(sse: Sse<S>) -> Self {
+    Self {
+      sse,
+      _payload: PhantomData,
+    }
+  }
+
+  /// Proxy for [`actix_web_lab::sse::Sse::with_keep_alive`](https://docs.rs/actix-web-lab/*/actix_web_lab/sse/struct.Sse.html#method.with_keep_alive).
+  pub fn with_keep_alive(mut self, keep_alive_period: std::time::Duration) -> Self {
+    self.sse = self.sse.with_keep_alive(keep_alive_period);
+    self
+  }
+
+  /// Proxy for [`actix_web_lab::sse::Sse::with_retry_duration`](https://docs.rs/actix-web-lab/*/actix_web_lab/sse/struct.Sse.html#method.with_retry_duration).
+  pub fn with_retry_duration(mut self, retry: std::time::Duration) -> Self {
+    self.sse = self.sse.with_retry_duration(retry);
+    self
+  }
+}
+
+impl<T, S> From<Sse<S>> for TypedSse<T, S> {
+  fn from(sse: Sse<S>) -> Self {
+    Self::new(sse)
+  }
+}
+
+impl<T, S> Responder for TypedSse<T, S>
+where
+  Sse<S>: Responder<Body = BoxBody>,
+{
+  type Body = BoxBody;
+
+  fn respond_to(self, req: &HttpRequest) -> HttpResponse<Self::Body> {
+    self.sse.respond_to(req)
+  }
+}
+
+impl<T, S> ApiComponent for TypedSse<T, S>
+where
+  T: ApiSseEvent,
+{
+  fn content_type() -> String {
+    "text/event-stream".to_string()
+  }
+
+  fn child_schemas() -> Vec<(String, ReferenceOr<Schema>)> {
+    T::child_schemas()
+  }
+
+  fn raw_schema() -> Option<ReferenceOr<Schema>> {
+    T::raw_schema()
+  }
+
+  fn schema() -> Option<(String, ReferenceOr<Schema>)> {
+    T::schema()
+  }
+
+  fn examples() -> Vec<(String, Example)> {
+    T::examples()
+  }
+
+  fn responses(content_type: Option<String>) -> Option<Responses> {
+    let event_names = T::event_names();
+    let media_type = MediaType {
+      schema: T::response_schema(),
+      example: T::media_type_examples(),
+      extensions: if event_names.is_empty() {
+        Default::default()
+      } else {
+        std::iter::once((
+          "x-event-names".to_string(),
+          Value::Array(event_names.into_iter().map(Value::String).collect()),
+        ))
+        .collect()
+      },
+      ..Default::default()
+    };
+    let response = Response {
+      content: BTreeMap::from_iter(vec![(content_type.unwrap_or_else(Self::content_type), media_type)]),
+      ..Default::default()
+    };
+    Some(Responses {
+      responses: BTreeMap::from_iter(vec![("200".to_string(), ReferenceOr::Object(response))]),
+      ..Default::default()
+    })
+  }
+}
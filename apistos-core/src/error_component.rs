@@ -6,6 +6,16 @@ use std::collections::BTreeMap;
 pub trait ApiErrorComponent {
   fn schemas_by_status_code() -> BTreeMap<String, (String, ReferenceOr<Schema>)>;
   fn error_responses() -> Vec<(String, Response)>;
+
+  /// Name under which the error response of **`status_code`** is shared in `components.responses`, if any
+  fn error_response_name(_status_code: &str) -> Option<String> {
+    None
+  }
+
+  /// Schemas referenced by the schemas of [`schemas_by_status_code`](ApiErrorComponent::schemas_by_status_code)
+  fn error_child_schemas() -> Vec<(String, ReferenceOr<Schema>)> {
+    vec![]
+  }
 }
 
 #[cfg(feature = "actix")]
@@ -10,6 +10,21 @@ pub trait PathItemDefinition {
     Default::default()
   }
 
+  /// Name of the documented handler, used to generate `operationId` by name
+  fn handler_name() -> Option<&'static str> {
+    None
+  }
+
+  /// Names of the security schemes given scopes through `security_scope`, whether or not an argument requires them
+  fn security_scope_names() -> Vec<String> {
+    Default::default()
+  }
+
+  /// Status codes explicitly targeted by `response_header`, `link` or `response_example`, which should be documented by the operation
+  fn response_target_codes() -> Vec<u16> {
+    Default::default()
+  }
+
   fn components() -> Vec<Components> {
     Default::default()
   }
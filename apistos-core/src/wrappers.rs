@@ -51,6 +51,18 @@ where
     P::operation()
   }
 
+  fn handler_name() -> Option<&'static str> {
+    P::handler_name()
+  }
+
+  fn security_scope_names() -> Vec<String> {
+    P::security_scope_names()
+  }
+
+  fn response_target_codes() -> Vec<u16> {
+    P::response_target_codes()
+  }
+
   fn components() -> Vec<Components> {
     P::components()
   }
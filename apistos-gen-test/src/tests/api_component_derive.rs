@@ -896,3 +896,151 @@ fn api_component_derive_named_enums_deep() {
     })
   );
 }
+
+#[test]
+#[allow(dead_code)]
+fn api_component_derive_examples() {
+  const DEFAULT_NAME: &str = "Rex";
+
+  #[derive(Serialize, JsonSchema, ApiComponent)]
+  #[openapi(
+    example = Pet::example(DEFAULT_NAME),
+    examples(name = "cat", summary = "A cat", value = Pet::example("Tom"))
+  )]
+  struct Pet {
+    name: String,
+  }
+
+  impl Pet {
+    fn example(name: &str) -> Self {
+      Self { name: name.to_owned() }
+    }
+  }
+
+  let examples = <Pet as ApiComponent>::examples();
+  let request_body = <actix_web::web::Json<Pet> as ApiComponent>::request_body();
+  let path_parameters = <actix_web::web::Path<Pet> as ApiComponent>::parameters();
+
+  assert_json_eq!(
+    serde_json::to_value(std::collections::BTreeMap::from_iter(examples)).expect("Unable to serialize as Json"),
+    json!({
+      "Pet": {
+        "value": {
+          "name": "Rex"
+        }
+      },
+      "cat": {
+        "summary": "A cat",
+        "value": {
+          "name": "Tom"
+        }
+      }
+    })
+  );
+  assert_json_eq!(
+    serde_json::to_value(request_body).expect("Unable to serialize as Json"),
+    json!({
+      "content": {
+        "application/json": {
+          "examples": {
+            "Pet": {
+              "$ref": "#/components/examples/Pet"
+            },
+            "cat": {
+              "$ref": "#/components/examples/cat"
+            }
+          },
+          "schema": {
+            "$ref": "#/components/schemas/Pet"
+          }
+        }
+      },
+      "required": true
+    })
+  );
+  assert_json_eq!(
+    serde_json::to_value(path_parameters).expect("Unable to serialize as Json"),
+    json!([
+      {
+        "example": "Rex",
+        "in": "path",
+        "name": "name",
+        "required": true,
+        "schema": {
+          "type": "string"
+        }
+      }
+    ])
+  );
+}
+
+#[test]
+#[allow(dead_code)]
+fn api_component_derive_unserializable_examples() {
+  #[derive(Serialize, JsonSchema, ApiComponent)]
+  #[openapi(
+    examples(name = "dog", value = json!({ "name": "Rex" })),
+    // json object keys must be strings
+    examples(name = "broken", value = std::collections::BTreeMap::from_iter([((1, 2), 3)]))
+  )]
+  struct Pet {
+    name: String,
+  }
+
+  let examples = <Pet as ApiComponent>::examples();
+  let request_body = <actix_web::web::Json<Pet> as ApiComponent>::request_body();
+
+  assert_eq!(
+    examples.into_iter().map(|(name, _)| name).collect::<Vec<_>>(),
+    vec!["dog".to_owned()]
+  );
+  assert_json_eq!(
+    serde_json::to_value(request_body)
+      .expect("Unable to serialize as Json")
+      .pointer("/content/application~1json/examples")
+      .cloned(),
+    Some(json!({
+      "dog": {
+        "$ref": "#/components/examples/dog"
+      }
+    }))
+  );
+}
+
+#[test]
+#[allow(dead_code)]
+fn api_component_derive_extensions() {
+  #[derive(JsonSchema, ApiComponent)]
+  #[openapi(
+    extension(name = "x-internal", value = true),
+    extension(name = "x-go-type", value = json!({ "type": "Pet", "import": { "package": "pets" } }))
+  )]
+  struct Pet {
+    name: String,
+  }
+
+  let (_, schema) = <Pet as ApiComponent>::schema().expect("schema should be defined");
+  let json = serde_json::to_value(schema).expect("Unable to serialize as Json");
+  assert_json_eq!(
+    json,
+    json!({
+      "properties": {
+        "name": {
+          "type": "string"
+        }
+      },
+      "required": [
+        "name"
+      ],
+      "title": "Pet",
+      "type": "object",
+      "x-go-type": {
+        "import": {
+          "package": "pets"
+        },
+        "type": "Pet"
+      },
+      "x-internal": true
+    })
+  );
+}
@@ -152,3 +152,16 @@ fn api_cookie_derive_deprecated_attribute() {
     })
   );
 }
+
+#[test]
+#[allow(dead_code)]
+fn api_cookie_derive_extensions() {
+  #[derive(JsonSchema, ApiCookie)]
+  #[openapi_cookie(name = "session", extension(name = "x-internal", value = true))]
+  struct SessionCookie(String);
+
+  let cookie_parameter = <SessionCookie as ApiComponent>::parameters();
+  let cookie_parameter = cookie_parameter.first().expect("missing parameter");
+  let json = serde_json::to_value(cookie_parameter).expect("Unable to serialize as Json");
+  assert_eq!(json.get("x-internal"), Some(&json!(true)));
+}
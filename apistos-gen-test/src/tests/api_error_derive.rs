@@ -1,7 +1,12 @@
+use actix_web::ResponseError;
+use actix_web::http::StatusCode;
 use apistos_core::ApiErrorComponent;
 use apistos_gen::ApiErrorComponent;
+use assert_json_diff::assert_json_eq;
 use schemars::JsonSchema;
+use serde_json::json;
 use std::collections::{BTreeMap, HashSet};
+use std::fmt::{Display, Formatter};
 
 #[test]
 #[allow(dead_code)]
@@ -103,3 +108,209 @@ fn api_component_with_schema() {
       .is_empty()
   );
 }
+
+#[test]
+#[allow(dead_code)]
+fn api_component_with_status_schemas() {
+  #[derive(JsonSchema)]
+  struct Resource {
+    id: String,
+  }
+
+  #[derive(JsonSchema)]
+  struct NotFoundBody {
+    resource: Resource,
+  }
+
+  #[derive(JsonSchema, ApiErrorComponent)]
+  #[openapi_error(
+    schema = Self,
+    status(code = 404, schema = NotFoundBody, description = "Unknown resource"),
+    status(code = 409)
+  )]
+  enum ErrorResponse {
+    NotFound(String),
+    Conflict(String),
+  }
+
+  let error_schemas = <ErrorResponse as ApiErrorComponent>::schemas_by_status_code();
+  let error_responses = BTreeMap::from_iter(<ErrorResponse as ApiErrorComponent>::error_responses());
+  let error_child_schemas = <ErrorResponse as ApiErrorComponent>::error_child_schemas();
+
+  assert_eq!(
+    error_schemas
+      .iter()
+      .map(|(code, (name, _))| (code.as_str(), name.as_str()))
+      .collect::<Vec<_>>(),
+    vec![("404", "NotFoundBody"), ("409", "ErrorResponse")]
+  );
+  assert_eq!(
+    error_child_schemas
+      .iter()
+      .map(|(name, _)| name.as_str())
+      .collect::<Vec<_>>(),
+    vec!["Resource"]
+  );
+  assert_json_eq!(
+    serde_json::to_value(error_responses).expect("Unable to serialize as Json"),
+    json!({
+      "404": {
+        "description": "Unknown resource",
+        "content": {
+          "application/json": {
+            "schema": {
+              "$ref": "#/components/schemas/NotFoundBody"
+            }
+          }
+        }
+      },
+      "409": {
+        "description": "Conflict",
+        "content": {
+          "application/json": {
+            "schema": {
+              "$ref": "#/components/schemas/ErrorResponse"
+            }
+          }
+        }
+      }
+    })
+  );
+}
+
+#[test]
+#[allow(dead_code)]
+fn api_component_with_component_name() {
+  mod pets {
+    use apistos_gen::ApiErrorComponent;
+
+    #[derive(ApiErrorComponent)]
+    #[openapi_error(component_name = "PetError", status(code = 404))]
+    pub(super) enum Error {
+      NotFound,
+    }
+  }
+
+  mod owners {
+    use apistos_gen::ApiErrorComponent;
+
+    #[derive(ApiErrorComponent)]
+    #[openapi_error(status(code = 404))]
+    pub(super) enum Error {
+      NotFound,
+    }
+  }
+
+  assert_eq!(
+    <pets::Error as ApiErrorComponent>::error_response_name("404"),
+    Some("PetError404".to_string())
+  );
+  assert_eq!(
+    <owners::Error as ApiErrorComponent>::error_response_name("404"),
+    Some("Error404".to_string())
+  );
+  assert_eq!(<pets::Error as ApiErrorComponent>::error_response_name("500"), None);
+}
+
+#[test]
+#[allow(dead_code)]
+fn api_component_with_variant_codes() {
+  #[derive(Debug, JsonSchema)]
+  struct PetNotFound {
+    pet_id: u32,
+  }
+
+  #[derive(Debug, JsonSchema)]
+  struct OwnerNotFound {
+    owner_id: u32,
+  }
+
+  #[derive(Debug, ApiErrorComponent)]
+  #[openapi_error(response_error, status(code = 401))]
+  enum ErrorResponse {
+    #[openapi_error(code = 404, description = "Unknown pet", schema)]
+    PetNotFound(PetNotFound),
+    #[openapi_error(code = 404, description = "Unknown owner", schema = OwnerNotFound)]
+    OwnerNotFound(u32),
+    #[openapi_error(code = 409)]
+    Conflict {
+      reason: String,
+    },
+    #[openapi_error(code = 502)]
+    Upstream(std::io::Error),
+    Internal,
+  }
+
+  impl Display for ErrorResponse {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+      write!(f, "{self:?}")
+    }
+  }
+
+  let error_schemas = <ErrorResponse as ApiErrorComponent>::schemas_by_status_code();
+  let error_responses = BTreeMap::from_iter(<ErrorResponse as ApiErrorComponent>::error_responses());
+  let error_child_schemas = <ErrorResponse as ApiErrorComponent>::error_child_schemas();
+
+  assert_json_eq!(
+    serde_json::to_value(error_responses).expect("Unable to serialize as Json"),
+    json!({
+      "401": {
+        "description": "Unauthorized"
+      },
+      "404": {
+        "description": "Unknown pet\n\nUnknown owner",
+        "content": {
+          "application/json": {
+            "schema": {
+              "$ref": "#/components/schemas/ErrorResponse404"
+            }
+          }
+        }
+      },
+      "409": {
+        "description": "Conflict"
+      },
+      "502": {
+        "description": "Bad Gateway"
+      }
+    })
+  );
+  assert_json_eq!(
+    serde_json::to_value(error_schemas).expect("Unable to serialize as Json"),
+    json!({
+      "404": [
+        "ErrorResponse404",
+        {
+          "oneOf": [
+            { "$ref": "#/components/schemas/PetNotFound" },
+            { "$ref": "#/components/schemas/OwnerNotFound" }
+          ]
+        }
+      ]
+    })
+  );
+  assert_eq!(
+    error_child_schemas
+      .iter()
+      .map(|(name, _)| name.as_str())
+      .collect::<Vec<_>>(),
+    vec!["PetNotFound", "OwnerNotFound"]
+  );
+
+  assert_eq!(
+    ErrorResponse::PetNotFound(PetNotFound { pet_id: 1 }).status_code(),
+    StatusCode::NOT_FOUND
+  );
+  assert_eq!(
+    ErrorResponse::Conflict {
+      reason: "Already exists".to_string()
+    }
+    .status_code(),
+    StatusCode::CONFLICT
+  );
+  assert_eq!(
+    ErrorResponse::Upstream(std::io::Error::other("Unreachable")).status_code(),
+    StatusCode::BAD_GATEWAY
+  );
+  assert_eq!(ErrorResponse::Internal.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+}
@@ -54,3 +54,16 @@ fn api_header_derive() {
     })
   );
 }
+
+#[test]
+#[allow(dead_code)]
+fn api_header_derive_extensions() {
+  #[derive(JsonSchema, ApiHeader)]
+  #[openapi_header(name = "X-Organization-Slug", extension(name = "x-internal", value = true))]
+  struct OrganizationSlug(String);
+
+  let header_parameter = <Header<OrganizationSlug> as ApiComponent>::parameters();
+  let header_parameter = header_parameter.first().expect("missing parameter");
+  let json = serde_json::to_value(header_parameter).expect("Unable to serialize as Json");
+  assert_eq!(json.get("x-internal"), Some(&json!(true)));
+}
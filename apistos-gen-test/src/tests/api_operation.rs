@@ -1,16 +1,20 @@
 use actix_multipart::form::MultipartForm;
+use actix_web::Either;
 use actix_web::dev::ServiceRequest;
 use actix_web::http::header::ContentType;
-use actix_web::web::Json;
-use actix_web::{Error, HttpResponse, Responder};
+use actix_web::web::{Form, Json};
+use actix_web::{Error, HttpResponse, Responder, ResponseError};
 use assert_json_diff::assert_json_eq;
 use schemars::_serde_json::json;
+use schemars::JsonSchema;
+use serde::Serialize;
 use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
 use uuid::Uuid;
 
 use apistos::actix::{AcceptedJson, CreatedJson, NoContent};
 use apistos_core::PathItemDefinition;
-use apistos_gen::api_operation;
+use apistos_gen::{ApiErrorComponent, api_operation};
 
 #[allow(clippy::todo)]
 mod test_models {
@@ -137,6 +141,11 @@ fn api_operation() {
     components,
     json!([
       {
+        "responses": {
+          "ErrorResponse405": {
+            "description": "Invalid input"
+          }
+        },
         "schemas": {
           "Test": {
             "properties": {
@@ -195,7 +204,7 @@ fn api_operation() {
           "description": ""
         },
         "405": {
-          "description": "Invalid input"
+          "$ref": "#/components/responses/ErrorResponse405"
         }
       },
       "summary": "Add a new pet to the store",
@@ -359,6 +368,11 @@ fn api_operation_simple_response() {
     components,
     json!([
       {
+        "responses": {
+          "ErrorResponse405": {
+            "description": "Invalid input"
+          }
+        },
         "schemas": {
           "Test": {
             "properties": {
@@ -405,7 +419,7 @@ fn api_operation_simple_response() {
           "description": ""
         },
         "405": {
-          "description": "Invalid input"
+          "$ref": "#/components/responses/ErrorResponse405"
         }
       },
       "summary": "Add a new pet to the store",
@@ -439,6 +453,11 @@ fn api_operation_without_parameters() {
     components,
     json!([
       {
+        "responses": {
+          "ErrorResponse405": {
+            "description": "Invalid input"
+          }
+        },
         "schemas": {
           "TestResult": {
             "properties": {
@@ -475,7 +494,7 @@ fn api_operation_without_parameters() {
           "description": ""
         },
         "405": {
-          "description": "Invalid input"
+          "$ref": "#/components/responses/ErrorResponse405"
         }
       },
       "summary": "Add a new pet to the store",
@@ -509,6 +528,11 @@ fn api_operation_no_content() {
     components,
     json!([
       {
+        "responses": {
+          "ErrorResponse405": {
+            "description": "Invalid input"
+          }
+        },
         "schemas": {
           "Test": {
             "properties": {
@@ -546,7 +570,7 @@ fn api_operation_no_content() {
           "description": ""
         },
         "405": {
-          "description": "Invalid input"
+          "$ref": "#/components/responses/ErrorResponse405"
         }
       },
       "summary": "Add a new pet to the store",
@@ -582,6 +606,11 @@ fn api_operation_created_json() {
     components,
     json!([
       {
+        "responses": {
+          "ErrorResponse405": {
+            "description": "Invalid input"
+          }
+        },
         "schemas": {
           "Test": {
             "properties": {
@@ -651,7 +680,7 @@ fn api_operation_created_json() {
           "description": ""
         },
         "405": {
-          "description": "Invalid input"
+          "$ref": "#/components/responses/ErrorResponse405"
         }
       },
       "summary": "Add a new pet to the store",
@@ -685,6 +714,11 @@ fn api_operation_created_json_simple_response() {
     components,
     json!([
       {
+        "responses": {
+          "ErrorResponse405": {
+            "description": "Invalid input"
+          }
+        },
         "schemas": {
           "Test": {
             "properties": {
@@ -731,7 +765,7 @@ fn api_operation_created_json_simple_response() {
           "description": ""
         },
         "405": {
-          "description": "Invalid input"
+          "$ref": "#/components/responses/ErrorResponse405"
         }
       },
       "summary": "Add a new pet to the store",
@@ -767,6 +801,11 @@ fn api_operation_accepted_json() {
     components,
     json!([
       {
+        "responses": {
+          "ErrorResponse405": {
+            "description": "Invalid input"
+          }
+        },
         "schemas": {
           "Test": {
             "properties": {
@@ -836,7 +875,7 @@ fn api_operation_accepted_json() {
           "description": ""
         },
         "405": {
-          "description": "Invalid input"
+          "$ref": "#/components/responses/ErrorResponse405"
         }
       },
       "summary": "Add a new pet to the store",
@@ -871,6 +910,11 @@ fn api_operation_deprecated() {
     components,
     json!([
       {
+        "responses": {
+          "ErrorResponse405": {
+            "description": "Invalid input"
+          }
+        },
         "schemas": {
           "Test": {
             "properties": {
@@ -940,7 +984,7 @@ fn api_operation_deprecated() {
           "description": ""
         },
         "405": {
-          "description": "Invalid input"
+          "$ref": "#/components/responses/ErrorResponse405"
         }
       },
       "summary": "Add a new pet to the store",
@@ -972,6 +1016,11 @@ fn api_operation_deprecated() {
     components,
     json!([
       {
+        "responses": {
+          "ErrorResponse405": {
+            "description": "Invalid input"
+          }
+        },
         "schemas": {
           "Test": {
             "properties": {
@@ -1042,7 +1091,7 @@ fn api_operation_deprecated() {
           "description": ""
         },
         "405": {
-          "description": "Invalid input"
+          "$ref": "#/components/responses/ErrorResponse405"
         }
       },
       "summary": "Add a new pet to the store",
@@ -1104,6 +1153,14 @@ fn api_operation_error() {
     components,
     json!([
       {
+        "responses": {
+          "MultipleErrorResponse401": {
+            "description": "Unauthorized"
+          },
+          "MultipleErrorResponse404": {
+            "description": "Not Found"
+          }
+        },
         "schemas": {
           "Test": {
             "properties": {
@@ -1173,10 +1230,10 @@ fn api_operation_error() {
           "description": ""
         },
         "401": {
-          "description": "Unauthorized"
+          "$ref": "#/components/responses/MultipleErrorResponse401"
         },
         "404": {
-          "description": "Not Found"
+          "$ref": "#/components/responses/MultipleErrorResponse404"
         }
       },
       "summary": "Add a new pet to the store",
@@ -1212,6 +1269,20 @@ fn api_operation_security() {
     components,
     json!([
       {
+        "responses": {
+          "MultipleErrorResponse401": {
+            "description": "Unauthorized"
+          },
+          "MultipleErrorResponse403": {
+            "description": "Forbidden"
+          },
+          "MultipleErrorResponse404": {
+            "description": "Not Found"
+          },
+          "MultipleErrorResponse405": {
+            "description": "Method Not Allowed"
+          }
+        },
         "schemas": {
           "Test": {
             "properties": {
@@ -1296,16 +1367,16 @@ fn api_operation_security() {
           "description": ""
         },
         "401": {
-          "description": "Unauthorized"
+          "$ref": "#/components/responses/MultipleErrorResponse401"
         },
         "403": {
-          "description": "Forbidden"
+          "$ref": "#/components/responses/MultipleErrorResponse403"
         },
         "404": {
-          "description": "Not Found"
+          "$ref": "#/components/responses/MultipleErrorResponse404"
         },
         "405": {
-          "description": "Method Not Allowed"
+          "$ref": "#/components/responses/MultipleErrorResponse405"
         }
       },
       "security": [
@@ -1345,6 +1416,20 @@ fn api_operation_multipart() {
     components,
     json!([
       {
+        "responses": {
+          "MultipleErrorResponse401": {
+            "description": "Unauthorized"
+          },
+          "MultipleErrorResponse403": {
+            "description": "Forbidden"
+          },
+          "MultipleErrorResponse404": {
+            "description": "Not Found"
+          },
+          "MultipleErrorResponse405": {
+            "description": "Method Not Allowed"
+          }
+        },
         "schemas": {
           "Test": {
             "properties": {
@@ -1382,16 +1467,16 @@ fn api_operation_multipart() {
           "description": ""
         },
         "401": {
-          "description": "Unauthorized"
+          "$ref": "#/components/responses/MultipleErrorResponse401"
         },
         "403": {
-          "description": "Forbidden"
+          "$ref": "#/components/responses/MultipleErrorResponse403"
         },
         "404": {
-          "description": "Not Found"
+          "$ref": "#/components/responses/MultipleErrorResponse404"
         },
         "405": {
-          "description": "Method Not Allowed"
+          "$ref": "#/components/responses/MultipleErrorResponse405"
         }
       },
       "summary": "Add a new pet to the store"
@@ -1424,6 +1509,20 @@ fn api_operation_consumes_produces() {
     components,
     json!([
       {
+        "responses": {
+          "MultipleErrorResponse401": {
+            "description": "Unauthorized"
+          },
+          "MultipleErrorResponse403": {
+            "description": "Forbidden"
+          },
+          "MultipleErrorResponse404": {
+            "description": "Not Found"
+          },
+          "MultipleErrorResponse405": {
+            "description": "Method Not Allowed"
+          }
+        },
         "schemas": {
           "Test": {
             "properties": {
@@ -1464,16 +1563,16 @@ fn api_operation_consumes_produces() {
           "description": ""
         },
         "401": {
-          "description": "Unauthorized"
+          "$ref": "#/components/responses/MultipleErrorResponse401"
         },
         "403": {
-          "description": "Forbidden"
+          "$ref": "#/components/responses/MultipleErrorResponse403"
         },
         "404": {
-          "description": "Not Found"
+          "$ref": "#/components/responses/MultipleErrorResponse404"
         },
         "405": {
-          "description": "Method Not Allowed"
+          "$ref": "#/components/responses/MultipleErrorResponse405"
         }
       },
       "summary": "Add a new pet to the store"
@@ -1506,6 +1605,11 @@ fn api_operation_root_vec() {
     components,
     json!([
       {
+        "responses": {
+          "ErrorResponse405": {
+            "description": "Invalid input"
+          }
+        },
         "schemas": {
           "Test": {
             "properties": {
@@ -1567,7 +1671,7 @@ fn api_operation_root_vec() {
           "description": ""
         },
         "405": {
-          "description": "Invalid input"
+          "$ref": "#/components/responses/ErrorResponse405"
         }
       },
       "summary": "Add a new pet to the store",
@@ -1608,6 +1712,11 @@ fn api_operation_actix_web_grant() {
     components,
     json!([
       {
+        "responses": {
+          "ErrorResponse405": {
+            "description": "Invalid input"
+          }
+        },
         "schemas": {
           "Test": {
             "properties": {
@@ -1669,7 +1778,7 @@ fn api_operation_actix_web_grant() {
           "description": ""
         },
         "405": {
-          "description": "Invalid input"
+          "$ref": "#/components/responses/ErrorResponse405"
         }
       },
       "summary": "Add a new pet to the store",
@@ -1722,7 +1831,7 @@ fn api_operation_no_security() {
           "description": ""
         },
         "405": {
-          "description": "Invalid input"
+          "$ref": "#/components/responses/ErrorResponse405"
         }
       },
       "security": [],
@@ -1774,16 +1883,16 @@ fn api_operation_no_security_with_optional_key() {
           "description": ""
         },
         "401": {
-          "description": "Unauthorized"
+          "$ref": "#/components/responses/MultipleErrorResponse401"
         },
         "403": {
-          "description": "Forbidden"
+          "$ref": "#/components/responses/MultipleErrorResponse403"
         },
         "404": {
-          "description": "Not Found"
+          "$ref": "#/components/responses/MultipleErrorResponse404"
         },
         "405": {
-          "description": "Method Not Allowed"
+          "$ref": "#/components/responses/MultipleErrorResponse405"
         }
       },
       "security": [],
@@ -1791,3 +1900,586 @@ fn api_operation_no_security_with_optional_key() {
     })
   );
 }
+
+#[test]
+#[allow(dead_code)]
+fn api_operation_error_schemas() {
+  #[derive(Serialize, JsonSchema)]
+  struct NotFoundBody {
+    resource: String,
+  }
+
+  #[derive(Serialize, Debug, JsonSchema, ApiErrorComponent)]
+  #[openapi_error(status(code = 404, schema = NotFoundBody), status(code = 405, schema = Self))]
+  enum ErrorResponse {
+    NotFound(String),
+    MethodNotAllowed(String),
+  }
+
+  impl Display for ErrorResponse {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+      write!(f, "{self:?}")
+    }
+  }
+
+  impl ResponseError for ErrorResponse {}
+
+  #[api_operation(tag = "pet", error_code = 404)]
+  pub(crate) async fn test() -> Result<Json<test_models::TestResult>, ErrorResponse> {
+    Ok(Json(test_models::TestResult { id: 0 }))
+  }
+
+  let components = __openapi_test::components();
+  let components = serde_json::to_value(components).expect("Unable to serialize as Json");
+  let operation = __openapi_test::operation();
+  let operation = serde_json::to_value(operation).expect("Unable to serialize as Json");
+
+  assert_json_eq!(
+    components,
+    json!([
+      {
+        "responses": {
+          "ErrorResponse404": {
+            "content": {
+              "application/json": {
+                "schema": {
+                  "$ref": "#/components/schemas/NotFoundBody"
+                }
+              }
+            },
+            "description": "Not Found"
+          }
+        },
+        "schemas": {
+          "NotFoundBody": {
+            "properties": {
+              "resource": {
+                "type": "string"
+              }
+            },
+            "required": [
+              "resource"
+            ],
+            "title": "NotFoundBody",
+            "type": "object"
+          },
+          "TestResult": {
+            "properties": {
+              "id": {
+                "format": "uint32",
+                "minimum": 0.0,
+                "type": "integer"
+              }
+            },
+            "required": [
+              "id"
+            ],
+            "title": "TestResult",
+            "type": "object"
+          }
+        }
+      }
+    ])
+  );
+  assert_json_eq!(
+    operation.get("responses"),
+    Some(&json!({
+      "200": {
+        "content": {
+          "application/json": {
+            "schema": {
+              "$ref": "#/components/schemas/TestResult"
+            }
+          }
+        },
+        "description": ""
+      },
+      "404": {
+        "$ref": "#/components/responses/ErrorResponse404"
+      }
+    }))
+  );
+}
+
+#[test]
+#[allow(dead_code)]
+fn api_operation_response_headers() {
+  /// Add a new pet to the store
+  #[api_operation(
+    response_header(name = "X-Rate-Limit-Remaining", schema = u32, description = "Remaining calls", required),
+    response_header(name = "Location", schema = String, code = 200)
+  )]
+  pub(crate) async fn test(
+    _body: Json<test_models::Test>,
+  ) -> Result<Json<test_models::TestResult>, test_models::ErrorResponse> {
+    Ok(Json(test_models::TestResult { id: 0 }))
+  }
+
+  let operation = __openapi_test::operation();
+  let operation = serde_json::to_value(operation).expect("Unable to serialize as Json");
+
+  assert_json_eq!(
+    operation.get("responses"),
+    Some(&json!({
+      "200": {
+        "content": {
+          "application/json": {
+            "schema": {
+              "$ref": "#/components/schemas/TestResult"
+            }
+          }
+        },
+        "description": "",
+        "headers": {
+          "Location": {
+            "schema": {
+              "title": "String",
+              "type": "string"
+            },
+            "style": "simple"
+          },
+          "X-Rate-Limit-Remaining": {
+            "description": "Remaining calls",
+            "required": true,
+            "schema": {
+              "format": "uint32",
+              "minimum": 0.0,
+              "title": "uint32",
+              "type": "integer"
+            },
+            "style": "simple"
+          }
+        }
+      },
+      "405": {
+        "$ref": "#/components/responses/ErrorResponse405"
+      }
+    }))
+  );
+}
+
+#[test]
+#[allow(dead_code)]
+fn api_operation_explicit_responses() {
+  /// Find a pet
+  #[api_operation(
+    response(code = 200, body = test_models::TestResult, description = "The pet"),
+    response(code = 202, body = test_models::Test),
+    response(code = 304)
+  )]
+  pub(crate) async fn test() -> Result<HttpResponse, test_models::ErrorResponse> {
+    Ok(HttpResponse::NotModified().finish())
+  }
+
+  let components = __openapi_test::components();
+  let operation = __openapi_test::operation();
+  let operation = serde_json::to_value(operation).expect("Unable to serialize as Json");
+
+  let schemas = components
+    .first()
+    .map(|components| components.schemas.keys().cloned().collect::<Vec<_>>())
+    .unwrap_or_default();
+  assert_eq!(schemas, vec!["Test".to_owned(), "TestResult".to_owned()]);
+  assert_json_eq!(
+    operation.get("responses"),
+    Some(&json!({
+      "200": {
+        "content": {
+          "application/json": {
+            "schema": {
+              "$ref": "#/components/schemas/TestResult"
+            }
+          }
+        },
+        "description": "The pet"
+      },
+      "202": {
+        "content": {
+          "application/json": {
+            "schema": {
+              "$ref": "#/components/schemas/Test"
+            }
+          }
+        },
+        "description": "Accepted"
+      },
+      "304": {
+        "description": "Not Modified"
+      },
+      "405": {
+        "$ref": "#/components/responses/ErrorResponse405"
+      }
+    }))
+  );
+}
+
+#[test]
+#[allow(dead_code)]
+fn api_operation_unserializable_examples() {
+  /// Add a new pet to the store
+  #[api_operation(
+    request_example(name = "new_pet", value = json!({ "test": "Rex" })),
+    // json object keys must be strings
+    response_example(name = "created_pet", value = std::collections::BTreeMap::from_iter([((1, 2), 3)]))
+  )]
+  pub(crate) async fn test(
+    _body: Json<test_models::Test>,
+  ) -> Result<Json<test_models::TestResult>, test_models::ErrorResponse> {
+    Ok(Json(test_models::TestResult { id: 0 }))
+  }
+
+  let components = __openapi_test::components();
+  let operation = __openapi_test::operation();
+
+  let examples = components
+    .first()
+    .map(|components| components.examples.keys().cloned().collect::<Vec<_>>())
+    .unwrap_or_default();
+  assert_eq!(examples, vec!["new_pet".to_owned()]);
+  let operation = serde_json::to_value(operation).expect("Unable to serialize as Json");
+  assert_json_eq!(
+    operation.pointer("/requestBody/content/application~1json/examples"),
+    Some(&json!({
+      "new_pet": {
+        "$ref": "#/components/examples/new_pet"
+      }
+    }))
+  );
+  assert_eq!(
+    operation.pointer("/responses/200/content/application~1json/examples"),
+    None
+  );
+}
+
+#[test]
+#[allow(dead_code)]
+fn api_operation_multiple_content_types() {
+  /// Add a new pet to the store
+  #[api_operation]
+  pub(crate) async fn test(
+    _body: Either<Json<test_models::Test>, Form<test_models::TestResult>>,
+  ) -> Result<Json<test_models::TestResult>, test_models::ErrorResponse> {
+    Ok(Json(test_models::TestResult { id: 0 }))
+  }
+
+  let components = __openapi_test::components();
+  let operation = __openapi_test::operation();
+  let operation = serde_json::to_value(operation).expect("Unable to serialize as Json");
+
+  let schemas = components
+    .first()
+    .map(|components| components.schemas.keys().cloned().collect::<Vec<_>>())
+    .unwrap_or_default();
+  assert!(schemas.contains(&"Test".to_owned()), "{schemas:?}");
+  assert!(schemas.contains(&"TestResult".to_owned()), "{schemas:?}");
+  assert!(!schemas.contains(&"EitherTestOrTestResult".to_owned()), "{schemas:?}");
+  assert_json_eq!(
+    operation.get("requestBody"),
+    Some(&json!({
+      "content": {
+        "application/json": {
+          "schema": {
+            "$ref": "#/components/schemas/Test"
+          }
+        },
+        "application/x-www-form-urlencoded": {
+          "schema": {
+            "$ref": "#/components/schemas/TestResult"
+          }
+        }
+      },
+      "required": true
+    }))
+  );
+}
+
+#[test]
+#[allow(dead_code)]
+fn api_operation_multiple_consumes() {
+  /// Add a new pet to the store
+  #[api_operation(consumes = "application/json", consumes = "application/x-www-form-urlencoded")]
+  pub(crate) async fn test(
+    _body: Json<test_models::Test>,
+  ) -> Result<Json<test_models::TestResult>, test_models::ErrorResponse> {
+    Ok(Json(test_models::TestResult { id: 0 }))
+  }
+
+  let operation = __openapi_test::operation();
+  let operation = serde_json::to_value(operation).expect("Unable to serialize as Json");
+
+  assert_json_eq!(
+    operation.get("requestBody"),
+    Some(&json!({
+      "content": {
+        "application/json": {
+          "schema": {
+            "$ref": "#/components/schemas/Test"
+          }
+        },
+        "application/x-www-form-urlencoded": {
+          "schema": {
+            "$ref": "#/components/schemas/Test"
+          }
+        }
+      },
+      "required": true
+    }))
+  );
+}
+
+#[test]
+#[allow(dead_code)]
+fn api_operation_callbacks() {
+  /// Subscribe to pet events
+  #[api_operation(
+    callback(
+      name = "onEvent",
+      expression = "{$request.body#/callbackUrl}",
+      body = test_models::TestResult,
+      response(code = 204, description = "Event received")
+    ),
+    callback(name = "onDelete", expression = "{$request.body#/callbackUrl}/deleted", method = "delete")
+  )]
+  pub(crate) async fn test(_body: Json<test_models::Test>) -> Result<HttpResponse, test_models::ErrorResponse> {
+    Ok(HttpResponse::Created().finish())
+  }
+
+  let components = __openapi_test::components();
+  let operation = __openapi_test::operation();
+  let operation = serde_json::to_value(operation).expect("Unable to serialize as Json");
+
+  let schemas = components
+    .first()
+    .map(|components| components.schemas.keys().cloned().collect::<Vec<_>>())
+    .unwrap_or_default();
+  assert_eq!(schemas, vec!["Test".to_owned(), "TestResult".to_owned()]);
+  assert_json_eq!(
+    operation.get("callbacks"),
+    Some(&json!({
+      "onDelete": {
+        "{$request.body#/callbackUrl}/deleted": {
+          "delete": {
+            "responses": {
+              "200": {
+                "description": "OK"
+              }
+            }
+          }
+        }
+      },
+      "onEvent": {
+        "{$request.body#/callbackUrl}": {
+          "post": {
+            "requestBody": {
+              "content": {
+                "application/json": {
+                  "schema": {
+                    "$ref": "#/components/schemas/TestResult"
+                  }
+                }
+              },
+              "required": true
+            },
+            "responses": {
+              "204": {
+                "description": "Event received"
+              }
+            }
+          }
+        }
+      }
+    }))
+  );
+}
+
+#[test]
+#[allow(dead_code)]
+fn api_operation_links() {
+  /// Add a new pet to the store
+  #[api_operation(
+    link(
+      name = "GetPetById",
+      operation_id = "get_pet",
+      parameter(name = "id", expression = "$response.body#/id"),
+      description = "The created pet"
+    ),
+    link(name = "DeletePet", operation_id = "delete_pet", code = 201)
+  )]
+  pub(crate) async fn test(
+    _body: Json<test_models::Test>,
+  ) -> Result<Json<test_models::TestResult>, test_models::ErrorResponse> {
+    Ok(Json(test_models::TestResult { id: 0 }))
+  }
+
+  let operation = __openapi_test::operation();
+  let operation = serde_json::to_value(operation).expect("Unable to serialize as Json");
+
+  assert_json_eq!(
+    operation.pointer("/responses/200/links"),
+    Some(&json!({
+      "GetPetById": {
+        "description": "The created pet",
+        "operationId": "get_pet",
+        "parameters": {
+          "id": "$response.body#/id"
+        }
+      }
+    }))
+  );
+  assert_json_eq!(operation.pointer("/responses/405/links"), None::<&serde_json::Value>);
+}
+
+#[test]
+#[allow(dead_code)]
+fn api_operation_error_response_targets() {
+  /// Add a new pet to the store
+  #[api_operation(
+    response_header(name = "Retry-After", schema = u32, code = 405),
+    link(name = "GetPets", operation_id = "get_pets", code = 405)
+  )]
+  pub(crate) async fn test(
+    _body: Json<test_models::Test>,
+  ) -> Result<Json<test_models::TestResult>, test_models::ErrorResponse> {
+    Ok(Json(test_models::TestResult { id: 0 }))
+  }
+
+  let components = __openapi_test::components();
+  let operation = __openapi_test::operation();
+
+  let error_response = components
+    .first()
+    .and_then(|components| components.responses.get("ErrorResponse405").cloned());
+  assert_json_eq!(
+    serde_json::to_value(error_response).expect("Unable to serialize as Json"),
+    json!({
+      "description": "Invalid input"
+    })
+  );
+  let operation = serde_json::to_value(operation).expect("Unable to serialize as Json");
+  assert_json_eq!(
+    operation.pointer("/responses/405"),
+    Some(&json!({
+      "description": "Invalid input",
+      "headers": {
+        "Retry-After": {
+          "schema": {
+            "format": "uint32",
+            "minimum": 0.0,
+            "title": "uint32",
+            "type": "integer"
+          },
+          "style": "simple"
+        }
+      },
+      "links": {
+        "GetPets": {
+          "operationId": "get_pets"
+        }
+      }
+    }))
+  );
+  assert_eq!(__openapi_test::response_target_codes(), vec![405]);
+}
+
+#[test]
+#[allow(dead_code)]
+fn api_operation_extensions() {
+  /// Add a new pet to the store
+  #[api_operation(
+    extension(name = "x-codegen-request-body-name", value = "pet"),
+    extension(name = "x-amazon-apigateway-integration", value = json!({ "type": "http_proxy" }))
+  )]
+  pub(crate) async fn test(_body: Json<test_models::Test>) -> Result<HttpResponse, test_models::ErrorResponse> {
+    Ok(HttpResponse::Created().finish())
+  }
+
+  let operation = __openapi_test::operation();
+  let operation = serde_json::to_value(operation).expect("Unable to serialize as Json");
+
+  assert_eq!(operation.get("x-codegen-request-body-name"), Some(&json!("pet")));
+  assert_eq!(
+    operation.get("x-amazon-apigateway-integration"),
+    Some(&json!({ "type": "http_proxy" }))
+  );
+}
+
+#[test]
+#[allow(dead_code)]
+fn api_operation_examples() {
+  /// Add a new pet to the store
+  #[api_operation(
+    request_example(name = "new_pet", summary = "A new pet", value = json!({ "test": "Rex" })),
+    response_example(name = "created_pet", value = test_models::TestResult { id: 1 }, code = 200)
+  )]
+  pub(crate) async fn test(
+    _body: Json<test_models::Test>,
+  ) -> Result<Json<test_models::TestResult>, test_models::ErrorResponse> {
+    Ok(Json(test_models::TestResult { id: 0 }))
+  }
+
+  let components = __openapi_test::components();
+  let operation = __openapi_test::operation();
+
+  let examples = components
+    .first()
+    .map(|components| components.examples.clone())
+    .unwrap_or_default();
+  assert_json_eq!(
+    serde_json::to_value(examples).expect("Unable to serialize as Json"),
+    json!({
+      "created_pet": {
+        "value": {
+          "id": 1
+        }
+      },
+      "new_pet": {
+        "summary": "A new pet",
+        "value": {
+          "test": "Rex"
+        }
+      }
+    })
+  );
+  let operation = serde_json::to_value(operation).expect("Unable to serialize as Json");
+  assert_json_eq!(
+    operation.get("requestBody"),
+    Some(&json!({
+      "content": {
+        "application/json": {
+          "examples": {
+            "new_pet": {
+              "$ref": "#/components/examples/new_pet"
+            }
+          },
+          "schema": {
+            "$ref": "#/components/schemas/Test"
+          }
+        }
+      },
+      "required": true
+    }))
+  );
+  assert_json_eq!(
+    operation.get("responses"),
+    Some(&json!({
+      "200": {
+        "content": {
+          "application/json": {
+            "examples": {
+              "created_pet": {
+                "$ref": "#/components/examples/created_pet"
+              }
+            },
+            "schema": {
+              "$ref": "#/components/schemas/TestResult"
+            }
+          }
+        },
+        "description": ""
+      },
+      "405": {
+        "$ref": "#/components/responses/ErrorResponse405"
+      }
+    }))
+  );
+}
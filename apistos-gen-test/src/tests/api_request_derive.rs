@@ -0,0 +1,144 @@
+use actix_web::FromRequest;
+use actix_web::cookie::Cookie;
+use actix_web::test::TestRequest;
+use actix_web::web::Json;
+use assert_json_diff::assert_json_eq;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::json;
+
+use apistos_core::ApiComponent;
+use apistos_gen::{ApiComponent, ApiRequest};
+
+#[derive(Deserialize, JsonSchema, ApiComponent)]
+struct PetPath {
+  pet_id: u32,
+}
+
+#[derive(Deserialize, JsonSchema, ApiComponent)]
+struct Pagination {
+  limit: Option<u32>,
+}
+
+#[derive(Deserialize, JsonSchema, ApiComponent)]
+struct Pet {
+  name: String,
+}
+
+#[derive(ApiRequest)]
+struct UpdatePet {
+  #[path_params]
+  path: PetPath,
+  #[query]
+  pagination: Pagination,
+  #[header(name = "X-Org", description = "Organization of the current caller")]
+  organization: String,
+  #[cookie(name = "session")]
+  session: Option<String>,
+  #[body]
+  body: Json<Pet>,
+}
+
+#[test]
+fn api_request_derive() {
+  let parameters = <UpdatePet as ApiComponent>::parameters();
+  let request_body = <UpdatePet as ApiComponent>::request_body();
+  let child_schemas = <UpdatePet as ApiComponent>::child_schemas();
+
+  assert!(<UpdatePet as ApiComponent>::schema().is_none());
+  assert_eq!(
+    child_schemas.iter().map(|(name, _)| name.as_str()).collect::<Vec<_>>(),
+    vec!["Pet"]
+  );
+  assert_json_eq!(
+    serde_json::to_value(request_body).expect("Unable to serialize as Json"),
+    json!({
+      "content": {
+        "application/json": {
+          "schema": {
+            "$ref": "#/components/schemas/Pet"
+          }
+        }
+      },
+      "required": true
+    })
+  );
+  assert_json_eq!(
+    serde_json::to_value(parameters).expect("Unable to serialize as Json"),
+    json!([
+      {
+        "in": "path",
+        "name": "pet_id",
+        "required": true,
+        "schema": {
+          "format": "uint32",
+          "minimum": 0.0,
+          "type": "integer"
+        }
+      },
+      {
+        "in": "query",
+        "name": "limit",
+        "required": false,
+        "schema": {
+          "format": "uint32",
+          "minimum": 0.0,
+          "nullable": true,
+          "type": "integer"
+        }
+      },
+      {
+        "deprecated": false,
+        "description": "Organization of the current caller",
+        "in": "header",
+        "name": "X-Org",
+        "required": true,
+        "schema": {
+          "title": "String",
+          "type": "string"
+        },
+        "style": "simple"
+      },
+      {
+        "deprecated": false,
+        "in": "cookie",
+        "name": "session",
+        "required": false,
+        "schema": {
+          "title": "String",
+          "type": "string"
+        }
+      }
+    ])
+  );
+}
+
+#[actix_web::test]
+async fn api_request_derive_from_request() {
+  let (req, mut payload) = TestRequest::post()
+    .uri("/pets/1?limit=10")
+    .param("pet_id", "1")
+    .insert_header(("X-Org", "acme"))
+    .cookie(Cookie::new("session", "abc"))
+    .set_json(json!({ "name": "Rex" }))
+    .to_http_parts();
+
+  let request = UpdatePet::from_request(&req, &mut payload).await;
+  let request = request.expect("Unable to extract request");
+  assert_eq!(request.path.pet_id, 1);
+  assert_eq!(request.pagination.limit, Some(10));
+  assert_eq!(request.organization, "acme");
+  assert_eq!(request.session, Some("abc".to_owned()));
+  assert_eq!(request.body.name, "Rex");
+
+  let (req, mut payload) = TestRequest::post()
+    .uri("/pets/1")
+    .param("pet_id", "1")
+    .set_json(json!({ "name": "Rex" }))
+    .to_http_parts();
+  let error = UpdatePet::from_request(&req, &mut payload).await.err();
+  assert_eq!(
+    error.map(|error| error.to_string()),
+    Some("Missing header X-Org".to_owned())
+  );
+}
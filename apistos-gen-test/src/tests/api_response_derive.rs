@@ -0,0 +1,128 @@
+use actix_web::http::StatusCode;
+use actix_web::test::TestRequest;
+use actix_web::web::Json;
+use actix_web::{HttpResponse, Responder};
+use assert_json_diff::assert_json_eq;
+use schemars::JsonSchema;
+use serde::Serialize;
+use serde_json::json;
+
+use apistos_core::{ApiComponent, PathItemDefinition};
+use apistos_gen::{ApiComponent, ApiResponse, api_operation};
+
+#[test]
+#[allow(dead_code)]
+fn api_response_derive() {
+  #[derive(Serialize, JsonSchema, ApiComponent)]
+  struct Pet {
+    id: u32,
+  }
+
+  #[derive(ApiResponse)]
+  enum GetPet {
+    #[api_response(code = 200)]
+    Found(Json<Pet>),
+    #[api_response(code = 404, description = "Unknown pet")]
+    Missing,
+    #[api_response(code = 304, header = "ETag")]
+    NotModified(HttpResponse),
+  }
+
+  let child_schemas = <GetPet as ApiComponent>::child_schemas();
+  assert_eq!(
+    child_schemas.iter().map(|(name, _)| name.as_str()).collect::<Vec<_>>(),
+    vec!["Pet"]
+  );
+  assert!(<GetPet as ApiComponent>::schema().is_none());
+
+  let responses = <GetPet as ApiComponent>::responses(None);
+  assert_json_eq!(
+    serde_json::to_value(responses).expect("Unable to serialize as Json"),
+    json!({
+      "200": {
+        "content": {
+          "application/json": {
+            "schema": {
+              "$ref": "#/components/schemas/Pet"
+            }
+          }
+        },
+        "description": "OK"
+      },
+      "304": {
+        "description": "Not Modified",
+        "headers": {
+          "ETag": {
+            "schema": {
+              "title": "String",
+              "type": "string"
+            },
+            "style": "simple"
+          }
+        }
+      },
+      "404": {
+        "description": "Unknown pet"
+      }
+    })
+  );
+
+  let req = TestRequest::default().to_http_request();
+  assert_eq!(
+    GetPet::Found(Json(Pet { id: 1 })).respond_to(&req).status(),
+    StatusCode::OK
+  );
+  assert_eq!(GetPet::Missing.respond_to(&req).status(), StatusCode::NOT_FOUND);
+  let response = GetPet::NotModified(HttpResponse::Ok().insert_header(("ETag", "\"1\"")).finish()).respond_to(&req);
+  assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+  assert!(response.headers().contains_key("ETag"));
+}
+
+#[test]
+#[allow(dead_code)]
+fn api_response_derive_operation() {
+  #[derive(Serialize, JsonSchema, ApiComponent)]
+  struct Pet {
+    id: u32,
+  }
+
+  #[derive(ApiResponse)]
+  enum CreatePet {
+    #[api_response(code = 201)]
+    Created(Json<Pet>),
+    #[api_response(code = 202, description = "Creation is pending")]
+    Pending,
+  }
+
+  #[api_operation(produces = "application/vnd.pet+json")]
+  pub(crate) async fn test() -> CreatePet {
+    CreatePet::Pending
+  }
+
+  let components = __openapi_test::components();
+  let operation = __openapi_test::operation();
+
+  let schemas = components
+    .first()
+    .map(|components| components.schemas.keys().cloned().collect::<Vec<_>>())
+    .unwrap_or_default();
+  assert_eq!(schemas, vec!["Pet".to_owned()]);
+  assert_json_eq!(
+    serde_json::to_value(operation.responses).expect("Unable to serialize as Json"),
+    json!({
+      "201": {
+        "content": {
+          "application/vnd.pet+json": {
+            "schema": {
+              "$ref": "#/components/schemas/Pet"
+            }
+          }
+        },
+        "description": "Created"
+      },
+      "202": {
+        "description": "Creation is pending"
+      }
+    })
+  );
+}
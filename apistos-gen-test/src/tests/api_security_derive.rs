@@ -159,3 +159,27 @@ fn api_security_derive_openid_connect() {
     })
   );
 }
+
+#[test]
+#[allow(dead_code)]
+fn api_security_derive_extensions() {
+  #[derive(ApiSecurity)]
+  #[openapi_security(
+    scheme(security_type(api_key(name = "api_key", api_key_in = "header"))),
+    extension(name = "x-amazon-apigateway-authtype", value = "custom")
+  )]
+  struct ApiKeyTest;
+
+  let securities = ApiKeyTest::securities();
+  let security_scheme = securities.get("api_key_test").expect("Unable to find security scheme");
+  let json = serde_json::to_value(security_scheme).expect("Unable to serialize as Json");
+  assert_json_eq!(
+    json,
+    json!({
+      "in": "header",
+      "name": "api_key",
+      "type": "apiKey",
+      "x-amazon-apigateway-authtype": "custom"
+    })
+  );
+}
@@ -3,5 +3,7 @@ mod api_cookie_derive;
 mod api_error_derive;
 mod api_header_derive;
 mod api_operation;
+mod api_request_derive;
+mod api_response_derive;
 mod api_security_derive;
 mod api_type_derive;
@@ -0,0 +1,318 @@
+use darling::FromMeta;
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+use syn::{Data, Fields, GenericArgument, Generics, Ident, Meta, PathArguments, Type};
+
+const FIELD_ATTRIBUTES: &[&str] = &["path_params", "query", "header", "cookie", "body"];
+
+/// Parse `#[path_params]`, `#[query]`, `#[header(...)]`, `#[cookie(...)]` and `#[body]` attributes of struct fields.
+pub(crate) fn parse_api_request_fields(ident: &Ident, data: &Data) -> darling::Result<Vec<RequestField>> {
+  let fields = match data {
+    Data::Struct(data) => match &data.fields {
+      Fields::Named(fields) => &fields.named,
+      _ => {
+        return Err(
+          darling::Error::custom("ApiRequest can only be derived for structs with named fields").with_span(ident),
+        );
+      }
+    },
+    _ => return Err(darling::Error::custom("ApiRequest can only be derived for structs").with_span(ident)),
+  };
+
+  let mut errors = darling::Error::accumulator();
+  let mut request_fields = vec![];
+  for field in fields {
+    let Some(field_ident) = field.ident.clone() else {
+      continue;
+    };
+    let attributes = field
+      .attrs
+      .iter()
+      .filter(|attribute| FIELD_ATTRIBUTES.iter().any(|name| attribute.path().is_ident(name)))
+      .collect::<Vec<_>>();
+    let attribute = match attributes.as_slice() {
+      [attribute] => *attribute,
+      _ => {
+        errors.push(
+          darling::Error::custom(format!(
+            "Expected one and only one of {FIELD_ATTRIBUTES:?} attributes per field"
+          ))
+          .with_span(field),
+        );
+        continue;
+      }
+    };
+
+    let source = if attribute.path().is_ident("path_params") {
+      Ok(FieldSource::Path)
+    } else if attribute.path().is_ident("query") {
+      Ok(FieldSource::Query)
+    } else if attribute.path().is_ident("body") {
+      Ok(FieldSource::Body)
+    } else if attribute.path().is_ident("header") {
+      HeaderAttribute::from_meta(&attribute.meta).map(FieldSource::Header)
+    } else {
+      match &attribute.meta {
+        Meta::Path(_) => Ok(CookieAttribute::default()),
+        meta => CookieAttribute::from_meta(meta),
+      }
+      .map(|cookie| FieldSource::Cookie {
+        name: cookie.name.unwrap_or_else(|| field_ident.to_string()),
+        description: cookie.description,
+      })
+    };
+    let source = match source {
+      Ok(source) => source,
+      Err(e) => {
+        errors.push(e.with_span(attribute));
+        continue;
+      }
+    };
+    request_fields.push(RequestField {
+      ident: field_ident,
+      ty: field.ty.clone(),
+      source,
+    });
+  }
+
+  if request_fields
+    .iter()
+    .filter(|field| matches!(field.source, FieldSource::Body))
+    .count()
+    > 1
+  {
+    errors.push(darling::Error::custom("Expected at most one #[body] field").with_span(ident));
+  }
+  errors.finish_with(request_fields)
+}
+
+#[derive(FromMeta, Clone)]
+struct HeaderAttribute {
+  name: String,
+  description: Option<String>,
+}
+
+#[derive(FromMeta, Clone, Default)]
+struct CookieAttribute {
+  name: Option<String>,
+  description: Option<String>,
+}
+
+enum FieldSource {
+  Path,
+  Query,
+  Header(HeaderAttribute),
+  Cookie { name: String, description: Option<String> },
+  Body,
+}
+
+pub(crate) struct RequestField {
+  ident: Ident,
+  ty: Type,
+  source: FieldSource,
+}
+
+impl RequestField {
+  /// Tokens extracting this field from `req` and `payload`
+  fn extract(&self) -> TokenStream {
+    let ty = &self.ty;
+    let (inner_ty, optional) = match option_inner_type(ty) {
+      Some(inner_ty) => (inner_ty, true),
+      None => (ty, false),
+    };
+    let parse_value = |location: &str, name: &str, value: TokenStream| {
+      let missing = format!("Missing {location} {name}");
+      let invalid = format!("Invalid {location} {name}");
+      let parsed = quote! {
+        #value
+          .map(|value| {
+            value
+              .parse::<#inner_ty>()
+              .map_err(|_| actix_web::error::ErrorBadRequest(#invalid))
+          })
+          .transpose()?
+      };
+      if optional {
+        parsed
+      } else {
+        quote!(#parsed.ok_or_else(|| actix_web::error::ErrorBadRequest(#missing))?)
+      }
+    };
+
+    match &self.source {
+      FieldSource::Path => {
+        quote!(<actix_web::web::Path<#ty> as actix_web::FromRequest>::extract(&req).await?.into_inner())
+      }
+      FieldSource::Query => {
+        quote!(<actix_web::web::Query<#ty> as actix_web::FromRequest>::extract(&req).await?.into_inner())
+      }
+      FieldSource::Header(header) => {
+        let name = &header.name;
+        parse_value(
+          "header",
+          name,
+          quote!(req.headers().get(#name).and_then(|value| value.to_str().ok())),
+        )
+      }
+      FieldSource::Cookie { name, .. } => parse_value(
+        "cookie",
+        name,
+        quote!(req.cookie(#name).as_ref().map(|cookie| cookie.value())),
+      ),
+      FieldSource::Body => quote! {
+        <#ty as actix_web::FromRequest>::from_request(&req, &mut payload)
+          .await
+          .map_err(Into::<actix_web::Error>::into)?
+      },
+    }
+  }
+
+  /// Tokens documenting this field as parameters
+  fn parameters(&self) -> TokenStream {
+    let ty = &self.ty;
+    let parameter = |name: &str, description: &Option<String>, location: TokenStream, style: TokenStream| {
+      let description = match description {
+        None => quote!(None),
+        Some(description) => quote!(Some(#description.to_string())),
+      };
+      quote! {
+        parameters.push(apistos::paths::Parameter {
+          name: #name.to_string(),
+          _in: #location,
+          description: #description,
+          required: Some(<#ty as apistos::ApiComponent>::required()),
+          deprecated: Some(false),
+          style: #style,
+          definition: <#ty as apistos::ApiComponent>::schema()
+            .map(|(_, schema)| schema)
+            .or_else(<#ty as apistos::ApiComponent>::raw_schema)
+            .map(Box::new)
+            .map(apistos::paths::ParameterDefinition::Schema),
+          ..Default::default()
+        });
+      }
+    };
+
+    match &self.source {
+      FieldSource::Path => {
+        quote!(parameters.append(&mut <actix_web::web::Path<#ty> as apistos::ApiComponent>::parameters());)
+      }
+      FieldSource::Query => {
+        quote!(parameters.append(&mut <actix_web::web::Query<#ty> as apistos::ApiComponent>::parameters());)
+      }
+      FieldSource::Header(header) => parameter(
+        &header.name,
+        &header.description,
+        quote!(apistos::paths::ParameterIn::Header),
+        quote!(Some(apistos::paths::ParameterStyle::Simple)),
+      ),
+      FieldSource::Cookie { name, description } => parameter(
+        name,
+        description,
+        quote!(apistos::paths::ParameterIn::Cookie),
+        quote!(None),
+      ),
+      FieldSource::Body => quote!(),
+    }
+  }
+}
+
+/// `ApiComponent` implementation documenting every field as a parameter or as the request body.
+pub(crate) struct ApiRequest<'a> {
+  pub(crate) fields: &'a [RequestField],
+}
+
+impl ToTokens for ApiRequest<'_> {
+  fn to_tokens(&self, tokens: &mut TokenStream) {
+    let field_types = self.fields.iter().map(|field| &field.ty).collect::<Vec<_>>();
+    let body_schema = self
+      .fields
+      .iter()
+      .filter(|field| matches!(field.source, FieldSource::Body))
+      .map(|field| &field.ty)
+      .collect::<Vec<_>>();
+    let request_body = match body_schema.first() {
+      None => quote!(None),
+      Some(ty) => quote!(<#ty as apistos::ApiComponent>::request_body()),
+    };
+    let parameters = self.fields.iter().map(RequestField::parameters);
+
+    tokens.extend(quote! {
+      fn child_schemas() -> Vec<(String, apistos::reference_or::ReferenceOr<apistos::Schema>)> {
+        let mut schemas = vec![];
+        #(
+          schemas.extend(<#body_schema as apistos::ApiComponent>::schema());
+        )*
+        #(
+          schemas.append(&mut <#field_types as apistos::ApiComponent>::child_schemas());
+        )*
+        schemas
+      }
+
+      fn schema() -> Option<(String, apistos::reference_or::ReferenceOr<apistos::Schema>)> {
+        None
+      }
+
+      fn examples() -> Vec<(String, apistos::paths::Example)> {
+        let mut examples = vec![];
+        #(
+          examples.append(&mut <#body_schema as apistos::ApiComponent>::examples());
+        )*
+        examples
+      }
+
+      fn request_body() -> Option<apistos::paths::RequestBody> {
+        #request_body
+      }
+
+      fn parameters() -> Vec<apistos::paths::Parameter> {
+        let mut parameters = vec![];
+        #(#parameters)*
+        parameters
+      }
+    });
+  }
+}
+
+/// `FromRequest` implementation extracting every field from its documented location.
+pub(crate) fn from_request_impl(ident: &Ident, generics: &Generics, fields: &[RequestField]) -> TokenStream {
+  let field_idents = fields.iter().map(|field| &field.ident);
+  let extracts = fields.iter().map(RequestField::extract);
+
+  let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+  quote! {
+    #[automatically_derived]
+    impl #impl_generics actix_web::FromRequest for #ident #ty_generics #where_clause {
+      type Error = actix_web::Error;
+      type Future = std::pin::Pin<Box<dyn std::future::Future<Output = Result<Self, Self::Error>>>>;
+
+      #[allow(unused_mut, unused_variables)]
+      fn from_request(req: &actix_web::HttpRequest, payload: &mut actix_web::dev::Payload) -> Self::Future {
+        let req = req.clone();
+        let mut payload = payload.take();
+        Box::pin(async move {
+          Ok(Self {
+            #(#field_idents: #extracts,)*
+          })
+        })
+      }
+    }
+  }
+}
+
+fn option_inner_type(ty: &Type) -> Option<&Type> {
+  let Type::Path(type_path) = ty else {
+    return None;
+  };
+  let segment = type_path.path.segments.last()?;
+  if segment.ident != "Option" {
+    return None;
+  }
+  let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+    return None;
+  };
+  match arguments.args.first() {
+    Some(GenericArgument::Type(ty)) => Some(ty),
+    _ => None,
+  }
+}
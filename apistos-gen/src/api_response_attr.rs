@@ -0,0 +1,210 @@
+use actix_web::http::StatusCode;
+use darling::FromMeta;
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+use syn::{Data, Fields, Generics, Ident, Type};
+
+/// Parse `#[api_response(...)]` attributes of enum variants, every variant being a possible response.
+pub(crate) fn parse_api_response_variants(ident: &Ident, data: &Data) -> darling::Result<Vec<ResponseVariant>> {
+  let Data::Enum(data) = data else {
+    return Err(darling::Error::custom("ApiResponse can only be derived for enums").with_span(ident));
+  };
+
+  let mut errors = darling::Error::accumulator();
+  let mut variants = vec![];
+  for variant in &data.variants {
+    let attributes = variant
+      .attrs
+      .iter()
+      .filter(|attribute| attribute.path().is_ident("api_response"))
+      .map(|attribute| ApiResponseAttribute::from_meta(&attribute.meta))
+      .collect::<darling::Result<Vec<ApiResponseAttribute>>>();
+    let attributes = match attributes {
+      Ok(attributes) => attributes,
+      Err(e) => {
+        errors.push(e);
+        continue;
+      }
+    };
+    let attribute = match attributes.as_slice() {
+      [attribute] => attribute.clone(),
+      _ => {
+        errors.push(
+          darling::Error::custom("Expected one and only one #[api_response] attribute per variant").with_span(variant),
+        );
+        continue;
+      }
+    };
+    let status = match StatusCode::from_u16(attribute.code) {
+      Ok(status) => status,
+      Err(e) => {
+        errors.push(darling::Error::custom(format!("{e}")).with_span(variant));
+        continue;
+      }
+    };
+
+    let payload = match &variant.fields {
+      Fields::Unit => None,
+      Fields::Unnamed(fields) if fields.unnamed.len() == 1 => fields.unnamed.first().map(|field| field.ty.clone()),
+      _ => {
+        errors.push(
+          darling::Error::custom("ApiResponse variants must either be unit variants or wrap a single responder")
+            .with_span(variant),
+        );
+        continue;
+      }
+    };
+    variants.push(ResponseVariant {
+      ident: variant.ident.clone(),
+      code: attribute.code,
+      description: attribute
+        .description
+        .unwrap_or_else(|| status.canonical_reason().unwrap_or_default().to_owned()),
+      headers: attribute.headers,
+      payload,
+    });
+  }
+  errors.finish_with(variants)
+}
+
+#[derive(FromMeta, Clone)]
+struct ApiResponseAttribute {
+  code: u16,
+  description: Option<String>,
+  #[darling(multiple, rename = "header")]
+  headers: Vec<String>,
+}
+
+pub(crate) struct ResponseVariant {
+  ident: Ident,
+  code: u16,
+  description: String,
+  headers: Vec<String>,
+  /// Responder wrapped by newtype variants
+  payload: Option<Type>,
+}
+
+/// `ApiComponent` implementation documenting every variant as a response.
+pub(crate) struct ApiResponses<'a> {
+  pub(crate) variants: &'a [ResponseVariant],
+}
+
+impl ToTokens for ApiResponses<'_> {
+  fn to_tokens(&self, tokens: &mut TokenStream) {
+    let payloads = self
+      .variants
+      .iter()
+      .filter_map(|variant| variant.payload.as_ref())
+      .collect::<Vec<_>>();
+    let responses = self.variants.iter().map(|variant| {
+      let code = variant.code.to_string();
+      let description = &variant.description;
+      let headers = &variant.headers;
+      let content = match &variant.payload {
+        None => quote!(std::collections::BTreeMap::default()),
+        Some(payload) => quote! {
+          <#payload as apistos::ApiComponent>::response_schema()
+            .map(|schema| {
+              (
+                content_type.clone().unwrap_or_else(<#payload as apistos::ApiComponent>::content_type),
+                apistos::paths::MediaType {
+                  schema: Some(schema),
+                  example: <#payload as apistos::ApiComponent>::media_type_examples(),
+                  ..Default::default()
+                },
+              )
+            })
+            .into_iter()
+            .collect()
+        },
+      };
+      quote! {
+        (
+          #code.to_owned(),
+          apistos::reference_or::ReferenceOr::Object(apistos::paths::Response {
+            description: #description.to_owned(),
+            headers: std::collections::BTreeMap::from_iter(vec![#(
+              (
+                #headers.to_owned(),
+                apistos::reference_or::ReferenceOr::Object(apistos::paths::Header {
+                  definition: <String as apistos::ApiComponent>::raw_schema()
+                    .map(Box::new)
+                    .map(apistos::paths::ParameterDefinition::Schema),
+                  style: Some(apistos::paths::ParameterStyle::Simple),
+                  ..Default::default()
+                }),
+              ),
+            )*]),
+            content: #content,
+            ..Default::default()
+          }),
+        )
+      }
+    });
+
+    tokens.extend(quote! {
+      fn child_schemas() -> Vec<(String, apistos::reference_or::ReferenceOr<apistos::Schema>)> {
+        let mut schemas = vec![];
+        #(
+          schemas.extend(<#payloads as apistos::ApiComponent>::schema());
+          schemas.append(&mut <#payloads as apistos::ApiComponent>::child_schemas());
+        )*
+        schemas
+      }
+
+      fn schema() -> Option<(String, apistos::reference_or::ReferenceOr<apistos::Schema>)> {
+        None
+      }
+
+      fn examples() -> Vec<(String, apistos::paths::Example)> {
+        let mut examples = vec![];
+        #(
+          examples.append(&mut <#payloads as apistos::ApiComponent>::examples());
+        )*
+        examples
+      }
+
+      #[allow(unused_variables)]
+      fn responses(content_type: Option<String>) -> Option<apistos::paths::Responses> {
+        Some(apistos::paths::Responses {
+          responses: std::collections::BTreeMap::from_iter(vec![#(#responses,)*]),
+          ..Default::default()
+        })
+      }
+    });
+  }
+}
+
+/// `Responder` implementation setting the documented status code of each variant.
+pub(crate) fn responder_impl(ident: &Ident, generics: &Generics, variants: &[ResponseVariant]) -> TokenStream {
+  let arms = variants.iter().map(|variant| {
+    let variant_ident = &variant.ident;
+    let code = variant.code;
+    let status = quote!(actix_web::http::StatusCode::from_u16(#code).unwrap_or(actix_web::http::StatusCode::INTERNAL_SERVER_ERROR));
+    match &variant.payload {
+      None => quote!(Self::#variant_ident => actix_web::HttpResponse::build(#status).finish(),),
+      Some(_) => quote! {
+        Self::#variant_ident(responder) => {
+          let mut response = actix_web::Responder::respond_to(responder, req).map_into_boxed_body();
+          *response.status_mut() = #status;
+          response
+        }
+      },
+    }
+  });
+
+  let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+  quote! {
+    #[automatically_derived]
+    impl #impl_generics actix_web::Responder for #ident #ty_generics #where_clause {
+      type Body = actix_web::body::BoxBody;
+
+      #[allow(unused_variables)]
+      fn respond_to(self, req: &actix_web::HttpRequest) -> actix_web::HttpResponse<Self::Body> {
+        match self {
+          #(#arms)*
+        }
+      }
+    }
+  }
+}
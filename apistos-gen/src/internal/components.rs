@@ -1,3 +1,5 @@
+use crate::internal::examples::ExampleAttribute;
+use crate::operation_attr::{OperationCallback, OperationResponse, Produces, ResponseHeader};
 use proc_macro2::TokenStream;
 use quote::{ToTokens, quote};
 use syn::Type;
@@ -6,14 +8,39 @@ pub(crate) struct Components<'a> {
   pub(crate) args: &'a [Type],
   pub(crate) responder_wrapper: &'a TokenStream,
   pub(crate) error_codes: &'a [u16],
+  pub(crate) responses: &'a [OperationResponse],
+  pub(crate) response_headers: &'a [ResponseHeader],
+  pub(crate) request_examples: &'a [ExampleAttribute],
+  pub(crate) response_examples: &'a [(ExampleAttribute, Option<u16>)],
+  pub(crate) callbacks: &'a [OperationCallback],
+  pub(crate) produces: &'a [Produces],
 }
 
 impl ToTokens for Components<'_> {
   fn to_tokens(&self, tokens: &mut TokenStream) {
     let args = self.args;
     let responder_wrapper = self.responder_wrapper;
+    // bodies of explicitly declared responses, of produced content types and of callback requests and responses
+    let response_bodies = self
+      .responses
+      .iter()
+      .chain(self.callbacks.iter().flat_map(|callback| &callback.responses))
+      .filter_map(|response| response.body.as_ref())
+      .chain(self.produces.iter().filter_map(|produces| produces.body.as_ref()))
+      .chain(self.callbacks.iter().filter_map(|callback| callback.body.as_ref()))
+      .collect::<Vec<_>>();
+    let response_header_schemas = self.response_headers.iter().map(|header| &header.schema);
+    let operation_examples = self
+      .request_examples
+      .iter()
+      .chain(self.response_examples.iter().map(|(example, _)| example));
     let error_codes_filter = if self.error_codes.is_empty() {
-      quote!()
+      quote! {
+        for (_, s) in error_schemas {
+          schemas.push(s);
+        }
+        let error_responses = <#responder_wrapper>::error_responses();
+      }
     } else {
       let error_codes = self.error_codes;
       quote! {
@@ -26,6 +53,10 @@ impl ToTokens for Components<'_> {
             }
           }
         }
+        let error_responses = <#responder_wrapper>::error_responses()
+          .into_iter()
+          .filter(|(status, _)| status.parse::<u16>().is_ok_and(|status| available_error_codes.contains(&status)))
+          .collect::<Vec<_>>();
       }
     };
     tokens.extend(quote!(
@@ -46,14 +77,45 @@ impl ToTokens for Components<'_> {
           schemas.push(<#args>::schema());
         )*
         schemas.push(<#responder_wrapper>::schema());
+        #(
+          schemas.push(<#response_bodies>::schema());
+        )*
         let mut schemas = schemas.into_iter().flatten().collect::<Vec<(String, apistos::reference_or::ReferenceOr<apistos::Schema>)>>();
         #(
           schemas.append(&mut <#args>::child_schemas());
         )*
         schemas.append(&mut <#responder_wrapper>::child_schemas());
+        #(
+          schemas.append(&mut <#response_bodies>::child_schemas());
+        )*
+        #(
+          schemas.append(&mut <#response_header_schemas>::child_schemas());
+        )*
         let error_schemas = <#responder_wrapper>::error_schemas();
         #error_codes_filter
+        schemas.append(&mut <#responder_wrapper>::error_child_schemas());
         component_builder.schemas = std::collections::BTreeMap::from_iter(schemas);
+        for (status, response) in error_responses {
+          if let Some(name) = <#responder_wrapper>::error_response_name(&status) {
+            component_builder.responses.insert(name, apistos::reference_or::ReferenceOr::Object(response));
+          }
+        }
+
+        let mut examples: Vec<(String, apistos::paths::Example)> = vec![];
+        #(
+          examples.extend(#operation_examples);
+        )*
+        #(
+          examples.append(&mut <#args>::examples());
+        )*
+        examples.append(&mut <#responder_wrapper>::examples());
+        #(
+          examples.append(&mut <#response_bodies>::examples());
+        )*
+        component_builder.examples = examples
+          .into_iter()
+          .map(|(name, example)| (name, apistos::reference_or::ReferenceOr::Object(example)))
+          .collect();
         vec![component_builder]
       }
     ))
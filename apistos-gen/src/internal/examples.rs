@@ -0,0 +1,67 @@
+use darling::FromMeta;
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+
+/// Named example, `value` being any expression evaluating to a serializable value.
+///
+/// Tokens evaluate to an optional entry, examples whose value can't be serialized being skipped with a warning.
+#[derive(FromMeta, Clone)]
+pub(crate) struct ExampleAttribute {
+  pub(crate) name: String,
+  pub(crate) summary: Option<String>,
+  pub(crate) description: Option<String>,
+  pub(crate) value: syn::Expr,
+}
+
+impl ToTokens for ExampleAttribute {
+  fn to_tokens(&self, tokens: &mut TokenStream) {
+    let name = &self.name;
+    let summary = match &self.summary {
+      None => quote!(None),
+      Some(summary) => quote!(Some(#summary.to_string())),
+    };
+    let description = match &self.description {
+      None => quote!(None),
+      Some(description) => quote!(Some(#description.to_string())),
+    };
+    let value = &self.value;
+    tokens.extend(quote! {
+      apistos::spec::example(#name, #value).map(|(name, example)| {
+        (
+          name,
+          apistos::paths::Example {
+            summary: #summary,
+            description: #description,
+            ..example
+          },
+        )
+      })
+    });
+  }
+}
+
+/// Tokens referencing **`example`** from every media type of `content`, examples whose value can't be serialized not being part of the components
+pub(crate) fn reference_example(content: &TokenStream, example: &ExampleAttribute) -> TokenStream {
+  let name = &example.name;
+  let value = &example.value;
+  quote! {
+    if schemars::_serde_json::to_value(&#value).is_ok() {
+      for media_type in #content.values_mut() {
+        let reference = apistos::reference_or::ReferenceOr::Reference {
+          _ref: format!("#/components/examples/{}", #name),
+        };
+        match &mut media_type.example {
+          Some(apistos::paths::Examples::Examples(examples)) => {
+            examples.insert(#name.to_string(), reference);
+          }
+          example => {
+            *example = Some(apistos::paths::Examples::Examples(std::collections::BTreeMap::from_iter(vec![(
+              #name.to_string(),
+              reference,
+            )])));
+          }
+        }
+      }
+    }
+  }
+}
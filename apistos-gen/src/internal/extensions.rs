@@ -0,0 +1,52 @@
+use darling::FromMeta;
+use darling::ast::NestedMeta;
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+
+/// Specification extension, `value` being any expression evaluating to a serializable value.
+///
+/// Tokens evaluate to an optional entry, extensions whose value can't be serialized being skipped with a warning.
+#[derive(Clone)]
+pub(crate) struct ExtensionAttribute {
+  pub(crate) name: String,
+  pub(crate) value: syn::Expr,
+}
+
+#[derive(FromMeta)]
+struct ExtensionAttributeInternal {
+  name: String,
+  value: ExtensionValue,
+}
+
+/// Expression kept as written, string literals being string values rather than parsed expressions
+struct ExtensionValue(syn::Expr);
+
+impl FromMeta for ExtensionValue {
+  fn from_expr(expr: &syn::Expr) -> darling::Result<Self> {
+    Ok(Self(expr.clone()))
+  }
+}
+
+impl FromMeta for ExtensionAttribute {
+  fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+    let extension = ExtensionAttributeInternal::from_list(items)?;
+    if !extension.name.starts_with("x-") {
+      return Err(darling::Error::custom(format!(
+        "Extension `{}` should start with `x-`",
+        extension.name
+      )));
+    }
+    Ok(Self {
+      name: extension.name,
+      value: extension.value.0,
+    })
+  }
+}
+
+impl ToTokens for ExtensionAttribute {
+  fn to_tokens(&self, tokens: &mut TokenStream) {
+    let name = &self.name;
+    let value = &self.value;
+    tokens.extend(quote!(apistos::spec::extension(#name, #value)));
+  }
+}
@@ -12,6 +12,8 @@ use syn::{
 mod components;
 mod operation;
 
+pub(crate) mod examples;
+pub(crate) mod extensions;
 pub(crate) mod schemas;
 pub(crate) mod security;
 pub(crate) mod utils;
@@ -74,6 +76,7 @@ pub(crate) fn gen_open_api_impl(
       .join("\\\n");
 
     let operation = Operation {
+      handler_name: &item_ast.sig.ident,
       args: &args,
       responder_wrapper,
       operation_id: operation_attribute.operation_id.as_ref(),
@@ -90,13 +93,28 @@ pub(crate) fn gen_open_api_impl(
       no_security: operation_attribute.no_security,
       scopes: operation_attribute.scopes,
       error_codes: &operation_attribute.error_codes,
-      consumes: operation_attribute.consumes.as_ref(),
-      produces: operation_attribute.produces.as_ref(),
+      consumes: &operation_attribute.consumes,
+      produces: &operation_attribute.produces,
+      responses: &operation_attribute.responses,
+      response_headers: &operation_attribute.response_headers,
+      request_examples: &operation_attribute.request_examples,
+      response_examples: &operation_attribute.response_examples,
+      callbacks: &operation_attribute.callbacks,
+      links: &operation_attribute.links,
+      extensions: &operation_attribute.extensions,
+      external_docs: operation_attribute.external_docs.as_ref(),
+      servers: &operation_attribute.servers,
     };
     let components = Components {
       args: &args,
       responder_wrapper,
       error_codes: &operation_attribute.error_codes,
+      responses: &operation_attribute.responses,
+      response_headers: &operation_attribute.response_headers,
+      request_examples: &operation_attribute.request_examples,
+      response_examples: &operation_attribute.response_examples,
+      callbacks: &operation_attribute.callbacks,
+      produces: &operation_attribute.produces,
     };
 
     quote!(
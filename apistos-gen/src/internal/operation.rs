@@ -1,10 +1,17 @@
+use crate::internal::examples::{ExampleAttribute, reference_example};
+use crate::internal::extensions::ExtensionAttribute;
 use crate::internal::security::Security;
-use proc_macro2::TokenStream;
+use crate::operation_attr::{
+  ExternalDocs, OperationCallback, OperationResponse, OperationServer, Produces, ResponseHeader, ResponseLink,
+};
+use actix_web::http::StatusCode;
+use proc_macro2::{Ident, TokenStream};
 use quote::{ToTokens, quote};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use syn::Type;
 
 pub(crate) struct Operation<'a> {
+  pub(crate) handler_name: &'a Ident,
   pub(crate) args: &'a [Type],
   pub(crate) responder_wrapper: &'a TokenStream,
   pub(crate) operation_id: Option<&'a String>,
@@ -15,8 +22,17 @@ pub(crate) struct Operation<'a> {
   pub(crate) no_security: bool,
   pub(crate) scopes: BTreeMap<String, Vec<String>>,
   pub(crate) error_codes: &'a [u16],
-  pub(crate) consumes: Option<&'a String>,
-  pub(crate) produces: Option<&'a String>,
+  pub(crate) consumes: &'a [String],
+  pub(crate) produces: &'a [Produces],
+  pub(crate) responses: &'a [OperationResponse],
+  pub(crate) response_headers: &'a [ResponseHeader],
+  pub(crate) request_examples: &'a [ExampleAttribute],
+  pub(crate) response_examples: &'a [(ExampleAttribute, Option<u16>)],
+  pub(crate) callbacks: &'a [OperationCallback],
+  pub(crate) links: &'a [ResponseLink],
+  pub(crate) extensions: &'a [ExtensionAttribute],
+  pub(crate) external_docs: Option<&'a ExternalDocs>,
+  pub(crate) servers: &'a [OperationServer],
 }
 
 impl ToTokens for Operation<'_> {
@@ -86,41 +102,319 @@ impl ToTokens for Operation<'_> {
       }
     };
 
-    let consumes = if let Some(consumes) = self.consumes {
-      quote!(Some(#consumes.to_string()))
+    let request_body = if args.is_empty() {
+      quote!()
     } else {
-      quote!(None)
+      let consumes = self.consumes;
+      quote! {
+        // bodies of every argument are merged in a single request body, one media type per content type
+        let consumes: Vec<String> = vec![#(#consumes.to_string(),)*];
+        let mut operation_request_body: Option<apistos::paths::RequestBody> = None;
+        #(
+          if let Some(mut request_body) = <#args>::request_body() {
+            if !consumes.is_empty() {
+              // each overriding content type keeps its own media type if any, the first one otherwise
+              let first_media_type = request_body.content.values().next().cloned();
+              request_body.content = consumes
+                .iter()
+                .filter_map(|content_type| {
+                  request_body
+                    .content
+                    .get(content_type)
+                    .cloned()
+                    .or_else(|| first_media_type.clone())
+                    .map(|media_type| (content_type.clone(), media_type))
+                })
+                .collect::<std::collections::BTreeMap<String, apistos::paths::MediaType>>();
+            }
+            match operation_request_body.as_mut() {
+              None => operation_request_body = Some(request_body),
+              Some(operation_request_body) => operation_request_body.content.append(&mut request_body.content),
+            }
+          }
+        )*
+        if let Some(request_body) = operation_request_body {
+          operation_builder.request_body = Some(apistos::reference_or::ReferenceOr::Object(request_body));
+        }
+      }
     };
-    let produces = if let Some(produces) = self.produces {
-      quote!(Some(#produces.to_string()))
+    let produces = if let Some(produces) = self.produces.first() {
+      let content_type = &produces.content_type;
+      quote!(Some(#content_type.to_string()))
     } else {
       quote!(None)
     };
+    // a single content type without body is handled by the responder, see `responses(#produces)`
+    let produced_contents = if self.produces.iter().all(|produces| produces.body.is_none()) && self.produces.len() < 2 {
+      quote!()
+    } else {
+      let contents = self.produces.iter().map(|produces| {
+        let content_type = &produces.content_type;
+        let media_type = match &produces.body {
+          None => quote!(media_type.clone()),
+          Some(body) => quote! {
+            apistos::paths::MediaType {
+              schema: <#body>::response_schema(),
+              example: <#body>::media_type_examples(),
+              ..Default::default()
+            }
+          },
+        };
+        quote!((#content_type.to_owned(), #media_type))
+      });
+      let set_contents = quote!(response.content = std::collections::BTreeMap::from_iter(vec![#(#contents,)*]););
+      // the media type inferred from the responder is only needed for content types without body
+      let update_contents = if self.produces.iter().any(|produces| produces.body.is_none()) {
+        quote! {
+          if let Some(media_type) = response.content.values().next().cloned() {
+            #set_contents
+          }
+        }
+      } else {
+        quote! {
+          if !response.content.is_empty() {
+            #set_contents
+          }
+        }
+      };
+      quote! {
+        // success responses are documented for every produced content type
+        for (status, response) in operation_builder.responses.responses.iter_mut() {
+          if let apistos::reference_or::ReferenceOr::Object(response) = response {
+            if !status.starts_with('4') && !status.starts_with('5') {
+              #update_contents
+            }
+          }
+        }
+      }
+    };
+    let explicit_responses = if self.responses.is_empty() {
+      quote!()
+    } else {
+      let responses = self.responses.iter().map(|response| {
+        let response = explicit_response(response, &produces);
+        quote! {
+          let (status, response) = #response;
+          operation_builder.responses.responses.insert(status, response);
+        }
+      });
+      quote! {
+        // explicitly declared responses replace the ones inferred from the responder, error responses are kept
+        operation_builder
+          .responses
+          .responses
+          .retain(|status, _| status.starts_with('4') || status.starts_with('5') || status == "default");
+        #(#responses)*
+      }
+    };
+    let callbacks = self.callbacks.iter().map(|callback| {
+      let name = &callback.name;
+      let expression = &callback.expression;
+      let operation_type = callback.operation_type();
+      let summary = match &callback.summary {
+        None => quote!(None),
+        Some(summary) => quote!(Some(#summary.to_string())),
+      };
+      let description = match &callback.description {
+        None => quote!(None),
+        Some(description) => quote!(Some(#description.to_string())),
+      };
+      let request_body = match &callback.body {
+        None => quote!(None),
+        Some(body) => quote!(<#body>::request_body().map(apistos::reference_or::ReferenceOr::Object)),
+      };
+      // a callback request without declared responses is expected to be acknowledged with a 200
+      let responses = if callback.responses.is_empty() {
+        vec![quote! {
+          (
+            "200".to_owned(),
+            apistos::reference_or::ReferenceOr::Object(apistos::paths::Response {
+              description: "OK".to_owned(),
+              ..Default::default()
+            }),
+          )
+        }]
+      } else {
+        callback
+          .responses
+          .iter()
+          .map(|response| explicit_response(response, &quote!(None)))
+          .collect()
+      };
+      quote! {
+        let callback_operation = apistos::paths::Operation {
+          summary: #summary,
+          description: #description,
+          request_body: #request_body,
+          responses: apistos::paths::Responses {
+            responses: std::collections::BTreeMap::from_iter(vec![#(#responses,)*]),
+            ..Default::default()
+          },
+          ..Default::default()
+        };
+        let callback_path_item = apistos::paths::PathItem {
+          operations: apistos::IndexMap::from_iter(vec![(#operation_type, callback_operation)]),
+          ..Default::default()
+        };
+        operation_builder.callbacks.insert(
+          #name.to_owned(),
+          apistos::reference_or::ReferenceOr::Object(apistos::paths::Callback {
+            callbacks: std::collections::BTreeMap::from_iter(vec![(#expression.to_owned(), callback_path_item)]),
+            ..Default::default()
+          }),
+        );
+      }
+    });
+    let request_examples = self.request_examples.iter().map(|example| {
+      let reference = reference_example(&quote!(request_body.content), example);
+      quote! {
+        if let Some(apistos::reference_or::ReferenceOr::Object(request_body)) = operation_builder.request_body.as_mut() {
+          #reference
+        }
+      }
+    });
+    let response_examples = self.response_examples.iter().map(|(example, code)| {
+      let reference = reference_example(&quote!(response.content), example);
+      let responses = response_targets(*code);
+      quote! {
+        for response in #responses {
+          #reference
+        }
+      }
+    });
+    let response_headers = self.response_headers.iter().map(|header| {
+      let name = &header.name;
+      let schema = &header.schema;
+      let description = match &header.description {
+        None => quote!(None),
+        Some(description) => quote!(Some(#description.to_string())),
+      };
+      let required = if header.required {
+        quote!(Some(true))
+      } else {
+        quote!(None)
+      };
+      let responses = response_targets(header.code);
+      quote! {
+        let header = apistos::paths::Header {
+          required: #required,
+          description: #description,
+          definition: <#schema>::schema()
+            .map(|(_, schema)| schema)
+            .or_else(<#schema>::raw_schema)
+            .map(Box::new)
+            .map(apistos::paths::ParameterDefinition::Schema),
+          style: Some(apistos::paths::ParameterStyle::Simple),
+          ..Default::default()
+        };
+        for response in #responses {
+          response.headers.insert(#name.to_string(), apistos::reference_or::ReferenceOr::Object(header.clone()));
+        }
+      }
+    });
+    let links = self.links.iter().map(|link| {
+      let name = &link.name;
+      let operation_id = &link.operation_id;
+      let parameters = link.parameters.iter().map(|parameter| {
+        let name = &parameter.name;
+        let expression = &parameter.expression;
+        quote!((#name.to_owned(), apistos::paths::AnyOrExpression::Expression(#expression.to_owned())))
+      });
+      let request_body = match &link.request_body {
+        None => quote!(None),
+        Some(request_body) => quote!(Some(apistos::paths::AnyOrExpression::Expression(#request_body.to_owned()))),
+      };
+      let description = match &link.description {
+        None => quote!(None),
+        Some(description) => quote!(Some(#description.to_string())),
+      };
+      let responses = response_targets(link.code);
+      quote! {
+        let link = apistos::paths::Link {
+          operation_identifier: Some(apistos::paths::OperationIdentifier::OperationId(#operation_id.to_owned())),
+          parameters: std::collections::BTreeMap::from_iter(vec![#(#parameters,)*]),
+          request_body: #request_body,
+          description: #description,
+          ..Default::default()
+        };
+        for response in #responses {
+          response.links.insert(#name.to_string(), apistos::reference_or::ReferenceOr::Object(link.clone()));
+        }
+      }
+    });
+    let extensions = if self.extensions.is_empty() {
+      quote!()
+    } else {
+      let extensions = self.extensions;
+      quote!(operation_builder.extensions.extend([#(#extensions,)*].into_iter().flatten());)
+    };
+    let external_docs = match self.external_docs {
+      None => quote!(),
+      Some(external_docs) => {
+        let url = &external_docs.url;
+        let description = match &external_docs.description {
+          None => quote!(None),
+          Some(description) => quote!(Some(#description.to_string())),
+        };
+        quote! {
+          operation_builder.external_docs = Some(apistos::paths::ExternalDocumentation {
+            url: #url.to_string(),
+            description: #description,
+            ..Default::default()
+          });
+        }
+      }
+    };
+    let servers = self.servers.iter().map(|server| {
+      let url = &server.url;
+      let description = match &server.description {
+        None => quote!(None),
+        Some(description) => quote!(Some(#description.to_string())),
+      };
+      quote! {
+        operation_builder.servers.push(apistos::server::Server {
+          url: #url.to_string(),
+          description: #description,
+          ..Default::default()
+        });
+      }
+    });
+    let response_target_codes = self
+      .response_headers
+      .iter()
+      .map(|header| header.code)
+      .chain(self.links.iter().map(|link| link.code))
+      .chain(self.response_examples.iter().map(|(_, code)| *code))
+      .flatten()
+      .collect::<BTreeSet<u16>>();
+    // shared error responses are referenced from `components.responses`, they are inlined to only complete this operation
+    let inlined_error_responses = if response_target_codes.is_empty() {
+      quote!()
+    } else {
+      let statuses = response_target_codes.iter().map(ToString::to_string);
+      quote! {
+        let error_responses = <#responder_wrapper>::error_responses();
+        for status in [#(#statuses,)*] {
+          let error_response = error_responses
+            .iter()
+            .find(|(error_status, _)| error_status == status)
+            .map(|(_, response)| response.clone());
+          if let (Some(response @ apistos::reference_or::ReferenceOr::Reference { .. }), Some(error_response)) =
+            (operation_builder.responses.responses.get_mut(status), error_response)
+          {
+            *response = apistos::reference_or::ReferenceOr::Object(error_response);
+          }
+        }
+      }
+    };
+    let handler_name = self.handler_name.to_string();
+    let security_scope_names = self.scopes.keys();
     tokens.extend(quote!(
       fn operation() -> apistos::paths::Operation {
         use apistos::ApiComponent;
         let mut operation_builder = apistos::paths::Operation::default();
 
-        let mut body_requests: Vec<std::option::Option<apistos::paths::RequestBody>> = vec![];
-        #(
-          let mut request_body = <#args>::request_body();
-          let consumes: Option<String> = #consumes;
-          if let Some(consumes) = consumes {
-            request_body
-              .as_mut()
-              .map(|t|
-                t.content = t
-                  .content
-                  .values()
-                  .map(|v| (consumes.clone(), v.clone())).collect::<std::collections::BTreeMap<String, apistos::paths::MediaType>>()
-              );
-          }
-          body_requests.push(request_body);
-        )*
-        let body_requests = body_requests.into_iter().flatten().collect::<Vec<apistos::paths::RequestBody>>();
-        for body_request in body_requests {
-          operation_builder.request_body = Some(apistos::reference_or::ReferenceOr::Object(body_request));
-        }
+        #request_body
 
         let mut parameters = vec![];
         #(
@@ -134,6 +428,14 @@ impl ToTokens for Operation<'_> {
           #error_codes_filter
           operation_builder.responses = responses;
         }
+        #explicit_responses
+        #produced_contents
+        #inlined_error_responses
+        #(#response_headers)*
+        #(#links)*
+        #(#request_examples)*
+        #(#response_examples)*
+        #(#callbacks)*
 
         let securities = {
           #security
@@ -153,8 +455,86 @@ impl ToTokens for Operation<'_> {
 
         #tags
 
+        #external_docs
+        #(#servers)*
+        #extensions
+
         operation_builder
       }
+
+      fn handler_name() -> Option<&'static str> {
+        Some(#handler_name)
+      }
+
+      fn security_scope_names() -> Vec<String> {
+        vec![#(#security_scope_names.to_owned(),)*]
+      }
+
+      fn response_target_codes() -> Vec<u16> {
+        vec![#(#response_target_codes,)*]
+      }
     ))
   }
 }
+
+/// Tokens iterating over the responses of the operation targeted by an attribute with an optional **`code`**, every non error response being targeted without explicit code
+///
+/// Error responses targeted by code are inlined beforehand, see `response_target_codes`.
+fn response_targets(code: Option<u16>) -> TokenStream {
+  let status_filter = match code {
+    None => quote!(!status.starts_with('4') && !status.starts_with('5')),
+    Some(code) => {
+      let code = code.to_string();
+      quote!(status == #code)
+    }
+  };
+  quote! {
+    operation_builder
+      .responses
+      .responses
+      .iter_mut()
+      .filter_map(|(status, response)| match response {
+        apistos::reference_or::ReferenceOr::Object(response) if #status_filter => Some(response),
+        _ => None,
+      })
+  }
+}
+
+/// Tokens of a `(status, response)` tuple documenting an explicitly declared response, its body being served as **`produces`** or json
+fn explicit_response(response: &OperationResponse, produces: &TokenStream) -> TokenStream {
+  let code = response.code.to_string();
+  let description = response.description.clone().unwrap_or_else(|| {
+    StatusCode::from_u16(response.code)
+      .ok()
+      .and_then(|status| status.canonical_reason())
+      .unwrap_or_default()
+      .to_owned()
+  });
+  let content = match &response.body {
+    None => quote!(std::collections::BTreeMap::default()),
+    Some(body) => quote! {
+      {
+        let schema = <#body>::response_schema();
+        let content_type: Option<String> = #produces;
+        std::collections::BTreeMap::from_iter(vec![(
+          content_type.unwrap_or_else(|| "application/json".to_owned()),
+          apistos::paths::MediaType {
+            schema,
+            example: <#body>::media_type_examples(),
+            ..Default::default()
+          },
+        )])
+      }
+    },
+  };
+  quote! {
+    (
+      #code.to_owned(),
+      apistos::reference_or::ReferenceOr::Object(apistos::paths::Response {
+        description: #description.to_owned(),
+        content: #content,
+        ..Default::default()
+      }),
+    )
+  }
+}
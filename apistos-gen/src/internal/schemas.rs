@@ -1,8 +1,10 @@
+use crate::internal::extensions::ExtensionAttribute;
 use proc_macro2::TokenStream;
 use quote::{ToTokens, quote};
 
 pub(crate) struct Schemas {
   pub(crate) deprecated: bool,
+  pub(crate) extensions: Vec<ExtensionAttribute>,
 }
 
 impl ToTokens for Schemas {
@@ -19,6 +21,15 @@ impl ToTokens for Schemas {
       quote!()
     };
 
+    let extensions = if self.extensions.is_empty() {
+      quote!()
+    } else {
+      let extensions = &self.extensions;
+      quote! {
+        schema.schema.extensions.extend([#(#extensions,)*].into_iter().flatten());
+      }
+    };
+
     let update_metadata_title = quote!(match sch_obj.metadata.as_mut() {
       None => {
         sch_obj.metadata = Some(Box::new(schemars::schema::Metadata {
@@ -86,6 +97,7 @@ impl ToTokens for Schemas {
             #update_one_of_title
           }
           #deprecated
+          #extensions
           (
             schema_name,
             apistos::reference_or::ReferenceOr::Object(schemars::schema::Schema::Object(schema.schema))
@@ -2,13 +2,17 @@
 //!
 //! ⚠️ This crate is not indented to be used by itself. Please use [**apistos**](https://crates.io/crates/apistos) instead.
 
+use crate::api_request_attr::{ApiRequest, from_request_impl, parse_api_request_fields};
+use crate::api_response_attr::{ApiResponses, parse_api_response_variants, responder_impl};
 use crate::internal::schemas::Schemas;
 use crate::internal::utils::extract_deprecated_from_attr;
 use crate::internal::{gen_item_ast, gen_open_api_impl};
+use crate::openapi_component_attr::{ComponentExamples, parse_openapi_component_attrs};
 use crate::openapi_cookie_attr::parse_openapi_cookie_attrs;
-use crate::openapi_error_attr::parse_openapi_error_attrs;
+use crate::openapi_error_attr::{ErrorResponses, parse_openapi_error_attrs, parse_openapi_error_variant_attrs};
 use crate::openapi_header_attr::parse_openapi_header_attrs;
 use crate::openapi_security_attr::parse_openapi_security_attrs;
+use crate::openapi_sse_event_attr::parse_openapi_sse_event_attrs;
 use crate::operation_attr::parse_openapi_operation_attrs;
 use convert_case::{Case, Casing};
 use darling::Error;
@@ -18,11 +22,15 @@ use proc_macro2::Span;
 use quote::{format_ident, quote};
 use syn::{DeriveInput, GenericParam, Ident, ItemFn};
 
+mod api_request_attr;
+mod api_response_attr;
 mod internal;
+mod openapi_component_attr;
 mod openapi_cookie_attr;
 mod openapi_error_attr;
 mod openapi_header_attr;
 mod openapi_security_attr;
+mod openapi_sse_event_attr;
 mod operation_attr;
 
 const OPENAPI_STRUCT_PREFIX: &str = "__openapi_";
@@ -135,25 +143,78 @@ pub fn derive_api_type(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// Examples can be attached to the component. They are registered in `components.examples` and referenced from the request and response bodies,
+/// while parameters extracted from the component are documented using the matching property of the first example.
+///
+/// ```rust
+/// use apistos::ApiComponent;
+/// use schemars::JsonSchema;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize, JsonSchema, ApiComponent)]
+/// #[openapi(
+///   example = Pet::example("Rex"),
+///   examples(name = "cat", summary = "A cat", value = Pet::example("Tom"))
+/// )]
+/// pub struct Pet {
+///   pub name: String,
+/// }
+///
+/// impl Pet {
+///   fn example(name: &str) -> Self {
+///     Self { name: name.to_string() }
+///   }
+/// }
+/// ```
+///
+/// # `#[openapi(...)]` options:
+/// - `example = ...` an optional expression evaluating to a serializable value, such as a const or a function call, registered as an example named after the component
+/// - `examples(...)` an optional list of named examples with
+///   - `name = "..."` a **required** name
+///   - `value = ...` a **required** expression evaluating to a serializable value
+///   - `summary = "..."` an optional summary
+///   - `description = "..."` an optional description
+/// - `extension(...)` an optional list of [specification extensions](https://spec.openapis.org/oas/latest.html#specification-extensions) added to the schema, with
+///   - `name = "..."` a **required** name starting with `x-`
+///   - `value = ...` a **required** expression evaluating to a serializable value, e.g. a string literal or `json!(...)`
+///
 /// Because this macro requires [JsonSchema](https://docs.rs/schemars/latest/schemars/trait.JsonSchema.html), all attributes supported by [JsonSchema](https://docs.rs/schemars/latest/schemars/trait.JsonSchema.html) are forwarded to
 /// this implementation.
-#[proc_macro_derive(ApiComponent)]
+#[proc_macro_derive(ApiComponent, attributes(openapi))]
 pub fn derive_api_component(input: TokenStream) -> TokenStream {
   let input = syn::parse_macro_input!(input as DeriveInput);
   let DeriveInput {
-    attrs: _attrs,
+    attrs,
     ident,
     data: _data,
     generics,
     vis: _vis,
   } = input;
 
+  let openapi_component_attributes = match parse_openapi_component_attrs(&attrs) {
+    Ok(openapi_component_attributes) => openapi_component_attributes,
+    Err(e) => return TokenStream::from(e.write_errors()),
+  };
+  let extensions = openapi_component_attributes
+    .as_ref()
+    .map(|attribute| attribute.extensions.clone())
+    .unwrap_or_default();
+  let examples = ComponentExamples {
+    ident: &ident,
+    attribute: openapi_component_attributes,
+  };
+
   let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
-  let schema_impl = Schemas { deprecated: false };
+  let schema_impl = Schemas {
+    deprecated: false,
+    extensions,
+  };
   quote!(
     #[automatically_derived]
     impl #impl_generics apistos::ApiComponent for #ident #ty_generics #where_clause {
       #schema_impl
+
+      #examples
     }
   )
   .into()
@@ -189,6 +250,9 @@ pub fn derive_api_component(input: TokenStream) -> TokenStream {
 ///       - `scheme = "..."` a **required** parameter
 ///       - `bearer_format = "..."` a **required** parameter
 ///     - `open_id_connect(open_id_connect_url = "...")`
+/// - `extension(...)` an optional list of [specification extensions](https://spec.openapis.org/oas/latest.html#specification-extensions) added to the security scheme, with
+///   - `name = "..."` a **required** name starting with `x-`
+///   - `value = ...` a **required** expression evaluating to a serializable value, e.g. a string literal or `json!(...)`
 ///
 /// _To define multiple elements of a list, repeat the property multiple times_
 ///
@@ -302,6 +366,7 @@ pub fn derive_api_security(input: TokenStream) -> TokenStream {
 /// - `description = "..."` an optional description for the header
 /// - `required = false` an optional parameter, default value is false
 /// - `deprecated = false` an optional parameter, default value is false
+/// - `extension(...)` an optional list of [specification extensions](https://spec.openapis.org/oas/latest.html#specification-extensions) added to the parameter, with the same options as `ApiComponent`'s `extension`
 ///
 /// Because this macro requires [JsonSchema](https://docs.rs/schemars/latest/schemars/trait.JsonSchema.html), all attributes supported by [JsonSchema](https://docs.rs/schemars/latest/schemars/trait.JsonSchema.html) are forwarded to
 /// this implementation.
@@ -327,6 +392,7 @@ pub fn derive_api_header(input: TokenStream) -> TokenStream {
   let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
   let schema_impl = Schemas {
     deprecated: openapi_header_attributes.deprecated.unwrap_or_default(),
+    extensions: vec![],
   };
   quote!(
     #[automatically_derived]
@@ -367,6 +433,7 @@ pub fn derive_api_header(input: TokenStream) -> TokenStream {
 /// - `description = "..."` an optional description for the header
 /// - `required = false` an optional parameter, default value is false
 /// - `deprecated = false` an optional parameter, default value is false
+/// - `extension(...)` an optional list of [specification extensions](https://spec.openapis.org/oas/latest.html#specification-extensions) added to the parameter, with the same options as `ApiComponent`'s `extension`
 ///
 /// Because this macro requires [JsonSchema](https://docs.rs/schemars/latest/schemars/trait.JsonSchema.html), all attributes supported by [JsonSchema](https://docs.rs/schemars/latest/schemars/trait.JsonSchema.html) are forwarded to
 /// this implementation.
@@ -399,11 +466,60 @@ pub fn derive_api_cookie(input: TokenStream) -> TokenStream {
   .into()
 }
 
+/// Generates the OpenAPI documentation of a server-sent event payload, to be used with `apistos::actix::TypedSse` (requires the `lab_sse` feature).
+///
+/// This `#[derive]` macro should be used in combination with [`ApiComponent`](derive.ApiComponent.html), the payload being documented as the schema of the `text/event-stream` response.
+/// The macro supports at most one `openapi_sse_event`, events without name being sent as unnamed (`message`) events.
+///
+/// ```rust,ignore
+/// use apistos::ApiComponent;
+/// use apistos::actix::ApiSseEvent;
+/// use schemars::JsonSchema;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize, Debug, Clone, JsonSchema, ApiComponent, ApiSseEvent)]
+/// #[openapi_sse_event(event = "created", event = "updated")]
+/// pub struct PetUpdate {
+///   pub id: u32,
+/// }
+/// ```
+///
+/// # `#[openapi_sse_event(...)]` options:
+/// - `event = "..."` an optional list of event names (`event:` field) sent with this payload, documented through the `x-event-names` extension of the media type
+#[proc_macro_derive(ApiSseEvent, attributes(openapi_sse_event))]
+pub fn derive_api_sse_event(input: TokenStream) -> TokenStream {
+  let input = syn::parse_macro_input!(input as DeriveInput);
+  let DeriveInput {
+    attrs,
+    ident,
+    data: _data,
+    generics,
+    vis: _vis,
+  } = input;
+
+  let openapi_sse_event_attributes = match parse_openapi_sse_event_attrs(&attrs) {
+    Ok(openapi_sse_event_attributes) => openapi_sse_event_attributes,
+    Err(e) => return TokenStream::from(e.write_errors()),
+  };
+
+  let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+  quote!(
+    #[automatically_derived]
+    impl #impl_generics apistos::actix::ApiSseEvent for #ident #ty_generics #where_clause {
+      #openapi_sse_event_attributes
+    }
+  )
+  .into()
+}
+
 /// Generates a reusable OpenAPI error schema.
 ///
 /// This `#[derive]` macro should be used in combination with [`api_operation`](attr.api_operation.html).
 /// The macro only supports one `openapi_error`.
 ///
+/// Error responses are shared between operations through `components.responses`, each one being named after the error type and its status code (e.g. `ErrorResponse404`).
+/// Error types sharing the same name in different modules should be given a distinct `component_name`, responses declared several times with a different content being reported when the spec is generated.
+///
 /// ```rust
 /// use apistos::ApiErrorComponent;
 ///
@@ -422,10 +538,76 @@ pub fn derive_api_cookie(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// Error responses can document their body using a type implementing [`JsonSchema`](https://docs.rs/schemars/latest/schemars/trait.JsonSchema.html),
+/// either per status or by default for every status. The schema is registered in `components.schemas`.
+///
+/// ```rust
+/// use apistos::ApiErrorComponent;
+/// use schemars::JsonSchema;
+///
+/// #[derive(JsonSchema)]
+/// pub struct NotFoundBody {
+///   resource: String,
+/// }
+///
+/// #[derive(Clone, JsonSchema, ApiErrorComponent)]
+/// #[openapi_error(
+///   schema = Self,
+///   status(code = 404, schema = NotFoundBody, description = "Unknown resource"),
+///   status(code = 409)
+/// )]
+/// pub enum ErrorResponse {
+///   NotFound(String),
+///   Conflict(String),
+/// }
+/// ```
+///
 /// # `#[openapi_error(...)]` options:
+/// - `schema = Type` an optional default schema for the body of every status without its own schema, `Self` refers to the error type
+/// - `component_name = "..."` an optional prefix for the names of the error responses registered in `components.responses`, default is the name of the error type
 /// - `status(...)` a list of possible error status with
 ///   - `code = 000` a **required** http status code
 ///   - `description = "..."` an optional description, default is the canonical reason of the given status code
+///   - `schema = Type` an optional type implementing `JsonSchema` documenting the body of the error response
+/// - `response_error` generates the `actix_web::ResponseError` implementation, its `status_code` matching each variant to its documented status code.
+///   Variants without `#[openapi_error(code = ...)]` are answered with `500 Internal Server Error`, which is only documented when declared through `status(code = 500)`
+///
+/// Status codes can also be declared on enum variants, keeping the documentation close to the variants returning them.
+/// The payload of newtype variants documents the body of the response when requested through the `schema` flag, schemas of variants sharing the same status code being merged using `oneOf`.
+///
+/// ```rust
+/// use apistos::ApiErrorComponent;
+/// use schemars::JsonSchema;
+/// use std::fmt::{Display, Formatter};
+///
+/// #[derive(Debug, JsonSchema)]
+/// pub struct NotFoundBody {
+///   resource: String,
+/// }
+///
+/// #[derive(Debug, ApiErrorComponent)]
+/// #[openapi_error(response_error)]
+/// pub enum ErrorResponse {
+///   #[openapi_error(code = 404, description = "Unknown pet", schema)]
+///   PetNotFound(NotFoundBody),
+///   #[openapi_error(code = 404, description = "Unknown owner")]
+///   OwnerNotFound(String),
+///   #[openapi_error(code = 409)]
+///   Conflict,
+///   Internal(std::io::Error),
+/// }
+/// # impl Display for ErrorResponse {
+/// #   fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+/// #     write!(f, "{self:?}")
+/// #   }
+/// # }
+/// ```
+///
+/// # `#[openapi_error(...)]` variant options:
+/// - `code = 000` a **required** http status code
+/// - `description = "..."` an optional description, default is the canonical reason of the given status code
+/// - `schema = Type` an optional type implementing `JsonSchema` documenting the body of the error response
+/// - `schema` flag documenting the body of the error response with the payload of a newtype variant, which must implement `JsonSchema`
 ///
 /// _To define multiple elements of a list, repeat the property multiple times_
 #[proc_macro_derive(ApiErrorComponent, attributes(openapi_error))]
@@ -434,23 +616,182 @@ pub fn derive_api_error(input: TokenStream) -> TokenStream {
   let DeriveInput {
     attrs,
     ident,
-    data: _data,
+    data,
     generics,
     vis: _vis,
   } = input;
 
   let openapi_error_attributes = match parse_openapi_error_attrs(&attrs) {
-    Ok(Some(openapi_error_attributes)) => openapi_error_attributes,
-    Ok(None) => return compile_error(&missing_attribute_error("openapi_error", "ApiErrorComponent")),
+    Ok(openapi_error_attributes) => openapi_error_attributes,
+    Err(e) => return TokenStream::from(e.write_errors()),
+  };
+  let variant_errors = match parse_openapi_error_variant_attrs(&data) {
+    Ok(variant_errors) => variant_errors,
+    Err(e) => return TokenStream::from(e.write_errors()),
+  };
+  if openapi_error_attributes.is_none() && variant_errors.is_empty() {
+    return compile_error(&missing_attribute_error("openapi_error", "ApiErrorComponent"));
+  }
+  let error_responses = match ErrorResponses::new(ident.clone(), openapi_error_attributes, variant_errors) {
+    Ok(error_responses) => error_responses,
     Err(e) => return TokenStream::from(e.write_errors()),
   };
+  let response_error_impl = error_responses.response_error_impl(&generics);
 
   let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
   quote!(
     #[automatically_derived]
     impl #impl_generics apistos::ApiErrorComponent for #ident #ty_generics #where_clause {
-      #openapi_error_attributes
+      #error_responses
+    }
+
+    #response_error_impl
+  )
+  .into()
+}
+
+/// Generates an extractor aggregating every part of a request, along with its documentation.
+///
+/// This `#[derive]` macro should be used in combination with [`api_operation`](attr.api_operation.html), the decorated struct being an argument of the handler.
+/// It implements both `actix_web::FromRequest`, extracting each field from its location, and [`ApiComponent`](trait.ApiComponent.html), documenting the same parameters and request body as the corresponding extractors.
+///
+/// ```rust
+/// use actix_web::web::Json;
+/// use apistos::{ApiComponent, ApiRequest};
+/// use schemars::JsonSchema;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize, JsonSchema, ApiComponent)]
+/// pub struct PetPath {
+///   pub pet_id: u32,
+/// }
+///
+/// #[derive(Deserialize, JsonSchema, ApiComponent)]
+/// pub struct Pagination {
+///   pub limit: Option<u32>,
+/// }
+///
+/// #[derive(Deserialize, JsonSchema, ApiComponent)]
+/// pub struct Pet {
+///   pub name: String,
+/// }
+///
+/// #[derive(ApiRequest)]
+/// pub struct UpdatePet {
+///   #[path_params]
+///   pub path: PetPath,
+///   #[query]
+///   pub pagination: Pagination,
+///   #[header(name = "X-Org", description = "Organization of the current caller")]
+///   pub organization: String,
+///   #[cookie(name = "session")]
+///   pub session: Option<String>,
+///   #[body]
+///   pub body: Json<Pet>,
+/// }
+/// ```
+///
+/// # Field attributes:
+/// - `#[path_params]` a type implementing `Deserialize` extracted and documented as `actix_web::web::Path`. The attribute isn't named `path` as it would conflict with rust built-in `#[path]` attribute.
+/// - `#[query]` a type implementing `Deserialize` extracted and documented as `actix_web::web::Query`
+/// - `#[header(...)]` a type implementing `FromStr`, optional if wrapped in an `Option`, with
+///   - `name = "..."` a **required** header name
+///   - `description = "..."` an optional description
+/// - `#[cookie(...)]` a type implementing `FromStr`, optional if wrapped in an `Option`, with
+///   - `name = "..."` an optional cookie name, default is the field name
+///   - `description = "..."` an optional description
+/// - `#[body]` an extractor implementing `ApiComponent` documenting the request body, such as `Json`. Only one field can be the body.
+#[proc_macro_derive(ApiRequest, attributes(path_params, query, header, cookie, body))]
+pub fn derive_api_request(input: TokenStream) -> TokenStream {
+  let input = syn::parse_macro_input!(input as DeriveInput);
+  let DeriveInput {
+    attrs: _attrs,
+    ident,
+    data,
+    generics,
+    vis: _vis,
+  } = input;
+
+  let fields = match parse_api_request_fields(&ident, &data) {
+    Ok(fields) => fields,
+    Err(e) => return TokenStream::from(e.write_errors()),
+  };
+  let api_request = ApiRequest { fields: &fields };
+  let from_request_impl = from_request_impl(&ident, &generics, &fields);
+
+  let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+  quote!(
+    #[automatically_derived]
+    impl #impl_generics apistos::ApiComponent for #ident #ty_generics #where_clause {
+      #api_request
+    }
+
+    #from_request_impl
+  )
+  .into()
+}
+
+/// Generates the documentation of every response of an enum responder.
+///
+/// This `#[derive]` macro should be used in combination with [`api_operation`](attr.api_operation.html), the decorated enum being returned by the handler.
+/// It implements both [`ApiComponent`](trait.ApiComponent.html), documenting each variant as a response, and `actix_web::Responder`, sending each variant with its documented status code.
+///
+/// Variants are either unit variants, resulting in an empty body, or newtype variants wrapping a responder implementing `ApiComponent` which documents the body of the response.
+///
+/// ```rust
+/// use actix_web::web::Json;
+/// use apistos::{ApiComponent, ApiResponse};
+/// use schemars::JsonSchema;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize, JsonSchema, ApiComponent)]
+/// pub struct Pet {
+///   pub id: u32,
+/// }
+///
+/// #[derive(ApiResponse)]
+/// pub enum GetPet {
+///   #[api_response(code = 200)]
+///   Found(Json<Pet>),
+///   #[api_response(code = 404, description = "Unknown pet")]
+///   Missing,
+///   #[api_response(code = 304, header = "ETag")]
+///   NotModified,
+/// }
+/// ```
+///
+/// # `#[api_response(...)]` variant options:
+/// - `code = 000` a **required** http status code
+/// - `description = "..."` an optional description, default is the canonical reason of the given status code
+/// - `header = "..."` an optional list of headers sent along the response
+///
+/// _To define multiple elements of a list, repeat the property multiple times_
+#[proc_macro_derive(ApiResponse, attributes(api_response))]
+pub fn derive_api_response(input: TokenStream) -> TokenStream {
+  let input = syn::parse_macro_input!(input as DeriveInput);
+  let DeriveInput {
+    attrs: _attrs,
+    ident,
+    data,
+    generics,
+    vis: _vis,
+  } = input;
+
+  let variants = match parse_api_response_variants(&ident, &data) {
+    Ok(variants) => variants,
+    Err(e) => return TokenStream::from(e.write_errors()),
+  };
+  let api_responses = ApiResponses { variants: &variants };
+  let responder_impl = responder_impl(&ident, &generics, &variants);
+
+  let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+  quote!(
+    #[automatically_derived]
+    impl #impl_generics apistos::ApiComponent for #ident #ty_generics #where_clause {
+      #api_responses
     }
+
+    #responder_impl
   )
   .into()
 }
@@ -528,8 +869,53 @@ pub fn derive_api_error(input: TokenStream) -> TokenStream {
 ///       - `name = "..."` a mandatory name referencing one of the security definitions
 ///       - `scope(...)` a list of scopes applying to this operation
 ///   - `error_code = 00` an optional list of error codes to document only theses
-///   - `consumes = "..."` allow to override body content type
-///   - `produces = "..."` allow to override response content type
+///   - `consumes = "..."` an optional list of content types overriding the body content type, the body being documented for each of them (define consumes multiple times to add to the list)
+///   - `produces = "..."` an optional list of content types overriding the success responses content type (define produces multiple times to add to the list). A content type can also be declared as `produces(...)` with
+///       - `content_type = "..."` a mandatory content type
+///       - `body = Type` an optional type implementing `ApiComponent` describing the body served with this content type. Default is the body inferred from the handler's return type.
+///   - `response(...)` an optional list of explicitly documented responses, replacing the success responses inferred from the handler's return type. Error responses are kept.
+///       - `code = 00` a mandatory status code
+///       - `body = Type` an optional type implementing `ApiComponent` describing the response body
+///       - `description = "..."` an optional description. Default is the status code canonical reason.
+///   - `response_header(...)` an optional list of headers sent along responses with
+///       - `name = "..."` a mandatory header name
+///       - `schema = Type` a mandatory type implementing `ApiComponent` describing the header value
+///       - `description = "..."` an optional description
+///       - `required` a bool indicating the header is always sent
+///       - `code = 00` an optional status code restricting the header to this response. Default is every non error response. Error responses shared through `components.responses` are copied into the operation, a warning being logged when building the app if the operation doesn't document this status code.
+///   - `request_example(...)` an optional list of named examples of the request body, registered in `components.examples`, with
+///       - `name = "..."` a mandatory name
+///       - `value = ...` a mandatory expression evaluating to a serializable value
+///       - `summary = "..."` an optional summary
+///       - `description = "..."` an optional description
+///   - `response_example(...)` an optional list of named examples of the response body, registered in `components.examples`, with the same options as `request_example` and
+///       - `code = 00` an optional status code restricting the example to this response, as for `response_header`. Default is every non error response.
+///   - `callback(...)` an optional list of out-of-band requests sent by the API, related to this operation, with
+///       - `name = "..."` a mandatory name identifying the callback
+///       - `expression = "..."` a mandatory runtime expression evaluating to the callback url, e.g. `"{$request.body#/callbackUrl}"`
+///       - `method = "..."` an optional http method of the callback request. Default is `post`.
+///       - `summary = "..."` an optional summary
+///       - `description = "..."` an optional description
+///       - `body = Type` an optional type implementing `ApiComponent` describing the callback request body
+///       - `response(...)` an optional list of responses expected from the callback request, with the same options as the operation `response`. Default is a `200` response.
+///   - `link(...)` an optional list of links from responses to other operations, with
+///       - `name = "..."` a mandatory name identifying the link
+///       - `operation_id = "..."` a mandatory id of the linked operation. A warning is logged when building the app if no operation has this id.
+///       - `parameter(...)` an optional list of parameters of the linked operation, with
+///           - `name = "..."` a mandatory parameter name, optionally qualified by its location (e.g. `path.id`)
+///           - `expression = "..."` a mandatory runtime expression evaluating to the parameter value, e.g. `"$response.body#/id"`
+///       - `request_body = "..."` an optional runtime expression evaluating to the request body of the linked operation
+///       - `description = "..."` an optional description
+///       - `code = 00` an optional status code restricting the link to this response, as for `response_header`. Default is every non error response.
+///   - `external_docs(...)` an optional link to an external documentation of the operation, with
+///       - `url = "..."` a mandatory url
+///       - `description = "..."` an optional description
+///   - `server(...)` an optional list of alternative servers serving this operation, with
+///       - `url = "..."` a mandatory url
+///       - `description = "..."` an optional description
+///   - `extension(...)` an optional list of [specification extensions](https://spec.openapis.org/oas/latest.html#specification-extensions) added to the operation, with
+///       - `name = "..."` a mandatory name starting with `x-`
+///       - `value = ...` a mandatory expression evaluating to a serializable value, e.g. a string literal or `json!(...)`
 ///
 /// _To define multiple elements of a list, repeat the property multiple times_
 ///
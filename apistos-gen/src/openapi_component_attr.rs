@@ -0,0 +1,67 @@
+use crate::internal::examples::ExampleAttribute;
+use crate::internal::extensions::ExtensionAttribute;
+use darling::FromMeta;
+use proc_macro2::{Ident, TokenStream};
+use quote::{ToTokens, quote};
+use syn::Attribute;
+
+pub(crate) fn parse_openapi_component_attrs(attrs: &[Attribute]) -> darling::Result<Option<OpenapiComponentAttribute>> {
+  let component_attribute = attrs
+    .iter()
+    .filter(|attribute| attribute.path().is_ident("openapi"))
+    .map(|attribute| OpenapiComponentAttribute::from_meta(&attribute.meta))
+    .collect::<darling::Result<Vec<OpenapiComponentAttribute>>>();
+
+  match component_attribute {
+    Ok(component_attributes) if component_attributes.len() > 1 => {
+      Err(darling::Error::custom("Expected only one #[openapi] attribute"))
+    }
+    Ok(component_attributes) => Ok(component_attributes.first().cloned()),
+    Err(e) => Err(e),
+  }
+}
+
+#[derive(FromMeta, Clone)]
+pub(crate) struct OpenapiComponentAttribute {
+  /// Example named after the component
+  example: Option<syn::Expr>,
+  #[darling(multiple)]
+  examples: Vec<ExampleAttribute>,
+  #[darling(multiple, rename = "extension")]
+  pub(crate) extensions: Vec<ExtensionAttribute>,
+}
+
+/// Examples of a component, generating `ApiComponent::examples`.
+pub(crate) struct ComponentExamples<'a> {
+  pub(crate) ident: &'a Ident,
+  pub(crate) attribute: Option<OpenapiComponentAttribute>,
+}
+
+impl ToTokens for ComponentExamples<'_> {
+  fn to_tokens(&self, tokens: &mut TokenStream) {
+    let Some(attribute) = &self.attribute else {
+      return;
+    };
+    let mut examples = attribute.examples.clone();
+    if let Some(value) = &attribute.example {
+      examples.insert(
+        0,
+        ExampleAttribute {
+          name: self.ident.to_string(),
+          summary: None,
+          description: None,
+          value: value.clone(),
+        },
+      );
+    }
+
+    if examples.is_empty() {
+      return;
+    }
+    tokens.extend(quote! {
+      fn examples() -> Vec<(String, apistos::paths::Example)> {
+        [#(#examples,)*].into_iter().flatten().collect()
+      }
+    });
+  }
+}
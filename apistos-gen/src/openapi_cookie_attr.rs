@@ -1,3 +1,4 @@
+use crate::internal::extensions::ExtensionAttribute;
 use crate::internal::schemas::Schemas;
 use darling::FromMeta;
 use proc_macro2::TokenStream;
@@ -25,6 +26,7 @@ pub(crate) fn parse_openapi_cookie_attrs(
         description: attr.description,
         required: attr.required,
         deprecated: attr.deprecated.or(deprecated),
+        extensions: attr.extensions,
       }))
     }
     Err(e) => Err(e),
@@ -37,6 +39,8 @@ struct OpenapiCookieAttribute {
   description: Option<String>,
   required: Option<bool>,
   deprecated: Option<bool>,
+  #[darling(multiple, rename = "extension")]
+  extensions: Vec<ExtensionAttribute>,
 }
 
 #[derive(Clone)]
@@ -45,6 +49,7 @@ pub(crate) struct OpenapiCookieAttributeExtended {
   pub(crate) description: Option<String>,
   pub(crate) required: Option<bool>,
   pub(crate) deprecated: Option<bool>,
+  pub(crate) extensions: Vec<ExtensionAttribute>,
 }
 
 impl ToTokens for OpenapiCookieAttributeExtended {
@@ -58,9 +63,16 @@ impl ToTokens for OpenapiCookieAttributeExtended {
     let required = quote!(#required);
     let deprecated = self.deprecated.unwrap_or_default();
     let deprecated = quote!(#deprecated);
+    let extensions = if self.extensions.is_empty() {
+      quote!(Default::default())
+    } else {
+      let extensions = &self.extensions;
+      quote!(apistos::IndexMap::from_iter([#(#extensions,)*].into_iter().flatten()))
+    };
 
     let schema_impl = Schemas {
       deprecated: self.deprecated.unwrap_or_default(),
+      extensions: vec![],
     };
     tokens.extend(quote! {
       #schema_impl
@@ -82,6 +94,7 @@ impl ToTokens for OpenapiCookieAttributeExtended {
               .or_else(Self::raw_schema)
               .map(Box::new)
               .map(apistos::paths::ParameterDefinition::Schema),
+            extensions: #extensions,
             ..Default::default()
           }
         ]
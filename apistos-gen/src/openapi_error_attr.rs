@@ -2,7 +2,7 @@ use actix_web::http::StatusCode;
 use darling::FromMeta;
 use proc_macro2::{Span, TokenStream};
 use quote::{ToTokens, quote};
-use syn::Attribute;
+use syn::{Attribute, Data, Ident, Type, TypePath};
 
 pub(crate) fn parse_openapi_error_attrs(attrs: &[Attribute]) -> darling::Result<Option<OpenapiErrorAttribute>> {
   let error_attribute = attrs
@@ -20,43 +20,340 @@ pub(crate) fn parse_openapi_error_attrs(attrs: &[Attribute]) -> darling::Result<
   }
 }
 
+/// Parse `#[openapi_error(code = ...)]` attributes of enum variants, returning the variants with their error definition.
+pub(crate) fn parse_openapi_error_variant_attrs(data: &Data) -> darling::Result<Vec<VariantError>> {
+  let Data::Enum(data) = data else {
+    return Ok(vec![]);
+  };
+
+  let mut variant_errors = vec![];
+  for variant in &data.variants {
+    let attributes = variant
+      .attrs
+      .iter()
+      .filter(|attribute| attribute.path().is_ident("openapi_error"))
+      .map(|attribute| VariantErrorAttribute::from_meta(&attribute.meta))
+      .collect::<darling::Result<Vec<VariantErrorAttribute>>>()?;
+    if attributes.len() > 1 {
+      return Err(
+        darling::Error::custom("Expected only one #[openapi_error] attribute per variant").with_span(variant),
+      );
+    }
+    let Some(attribute) = attributes.into_iter().next() else {
+      continue;
+    };
+
+    let schema = match attribute.schema {
+      None => None,
+      Some(VariantSchema::Type(path)) => Some(path_type(path)),
+      // only newtype variants have a payload which can be documented without an explicit schema
+      Some(VariantSchema::Payload) => match &variant.fields {
+        syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+          fields.unnamed.first().map(|field| field.ty.clone())
+        }
+        _ => {
+          return Err(darling::Error::custom("`schema` without a type requires a newtype variant").with_span(variant));
+        }
+      },
+    };
+    variant_errors.push(VariantError {
+      ident: variant.ident.clone(),
+      definition: ErrorDefinition {
+        code: attribute.code,
+        description: attribute.description,
+        schema,
+      },
+    });
+  }
+  Ok(variant_errors)
+}
+
 #[derive(FromMeta, Clone)]
 pub(crate) struct OpenapiErrorAttribute {
+  /// Default schema of statuses without their own schema
+  pub(crate) schema: Option<syn::Path>,
+  /// Prefix of the names of the error responses and merged schemas registered in components, default to the name of the error type
+  pub(crate) component_name: Option<String>,
+  /// Generate the `ResponseError` implementation with a `status_code` matching the documented status codes
+  #[darling(default)]
+  pub(crate) response_error: bool,
   #[darling(multiple)]
-  pub(crate) status: Vec<ErrorDefinition>,
+  pub(crate) status: Vec<StatusAttribute>,
+}
+
+#[derive(FromMeta, Clone)]
+pub(crate) struct StatusAttribute {
+  pub(crate) code: u16,
+  pub(crate) description: Option<String>,
+  /// Type implementing `JsonSchema` documenting the body of the error response
+  pub(crate) schema: Option<syn::Path>,
+}
+
+#[derive(FromMeta, Clone)]
+pub(crate) struct VariantErrorAttribute {
+  pub(crate) code: u16,
+  pub(crate) description: Option<String>,
+  /// Type implementing `JsonSchema` documenting the body of the error response, or the payload of a newtype variant when used as a flag
+  pub(crate) schema: Option<VariantSchema>,
+}
+
+#[derive(Clone)]
+pub(crate) enum VariantSchema {
+  /// `schema` flag, documenting the body with the payload of the variant
+  Payload,
+  /// `schema = Type`
+  Type(syn::Path),
 }
 
-impl ToTokens for OpenapiErrorAttribute {
+impl FromMeta for VariantSchema {
+  fn from_meta(item: &syn::Meta) -> darling::Result<Self> {
+    match item {
+      syn::Meta::Path(_) => Ok(Self::Payload),
+      _ => syn::Path::from_meta(item).map(Self::Type),
+    }
+  }
+}
+
+pub(crate) struct VariantError {
+  ident: Ident,
+  definition: ErrorDefinition,
+}
+
+#[derive(Clone)]
+struct ErrorDefinition {
+  code: u16,
+  description: Option<String>,
+  schema: Option<Type>,
+}
+
+/// Error responses of a type, status codes declared several times being merged.
+pub(crate) struct ErrorResponses {
+  ident: Ident,
+  /// Prefix of the names of the components generated for this type
+  component_name: String,
+  statuses: Vec<ErrorStatus>,
+  /// Variants with their status code
+  variants: Vec<(Ident, u16)>,
+  response_error: bool,
+}
+
+struct ErrorStatus {
+  code: u16,
+  descriptions: Vec<String>,
+  schemas: Vec<Type>,
+}
+
+impl ErrorResponses {
+  pub(crate) fn new(
+    ident: Ident,
+    attribute: Option<OpenapiErrorAttribute>,
+    variant_errors: Vec<VariantError>,
+  ) -> darling::Result<Self> {
+    let attribute = attribute.unwrap_or(OpenapiErrorAttribute {
+      schema: None,
+      component_name: None,
+      response_error: false,
+      status: vec![],
+    });
+    let component_name = attribute.component_name.unwrap_or_else(|| ident.to_string());
+    let mut errors = darling::Error::accumulator();
+
+    let definitions = attribute
+      .status
+      .into_iter()
+      .map(|status| ErrorDefinition {
+        code: status.code,
+        description: status.description,
+        schema: status.schema.map(path_type),
+      })
+      .chain(variant_errors.iter().map(|variant| variant.definition.clone()));
+    let mut statuses: Vec<ErrorStatus> = vec![];
+    for definition in definitions {
+      if let Err(e) = StatusCode::from_u16(definition.code) {
+        errors.push(darling::Error::custom(format!("{e}")));
+        continue;
+      }
+      let index = match statuses.iter().position(|status| status.code == definition.code) {
+        Some(index) => index,
+        None => {
+          statuses.push(ErrorStatus {
+            code: definition.code,
+            descriptions: vec![],
+            schemas: vec![],
+          });
+          statuses.len() - 1
+        }
+      };
+      let Some(status) = statuses.get_mut(index) else {
+        continue;
+      };
+      if let Some(description) = definition.description {
+        if !status.descriptions.contains(&description) {
+          status.descriptions.push(description);
+        }
+      }
+      if let Some(schema) = definition.schema {
+        let key = schema.to_token_stream().to_string();
+        if !status
+          .schemas
+          .iter()
+          .any(|existing| existing.to_token_stream().to_string() == key)
+        {
+          status.schemas.push(schema);
+        }
+      }
+    }
+
+    if let Some(default_schema) = attribute.schema.map(path_type) {
+      for status in statuses.iter_mut().filter(|status| status.schemas.is_empty()) {
+        status.schemas.push(default_schema.clone());
+      }
+    }
+
+    let variants = variant_errors
+      .into_iter()
+      .map(|variant| (variant.ident, variant.definition.code))
+      .collect::<Vec<_>>();
+    if attribute.response_error && variants.is_empty() && statuses.len() != 1 {
+      errors.push(darling::Error::custom(
+        "response_error requires either variant level codes or a single status",
+      ));
+    }
+
+    errors.finish_with(Self {
+      ident,
+      component_name,
+      statuses,
+      variants,
+      response_error: attribute.response_error,
+    })
+  }
+}
+
+impl ToTokens for ErrorResponses {
   fn to_tokens(&self, tokens: &mut TokenStream) {
-    let defs = &self.status;
+    let defs = self.statuses.iter().map(|status| ErrorStatusTokens {
+      component_name: &self.component_name,
+      status,
+    });
+    let defs = quote!(#(#defs,)*);
+    let mut child_schema_types = vec![];
+    let mut referenced_schema_types = vec![];
+    for status in &self.statuses {
+      child_schema_types.extend(status.schemas.iter());
+      if status.schemas.len() > 1 {
+        referenced_schema_types.extend(status.schemas.iter());
+      }
+    }
+
+    let status_codes = self.statuses.iter().map(|status| status.code.to_string());
+    let response_names = self
+      .statuses
+      .iter()
+      .map(|status| format!("{}{}", self.component_name, status.code));
+
     tokens.extend(quote! {
+      fn error_response_name(status_code: &str) -> Option<String> {
+        match status_code {
+          #(#status_codes => Some(#response_names.to_string()),)*
+          _ => None,
+        }
+      }
+
       fn error_responses() -> Vec<(String, apistos::paths::Response)> {
-        let responses: Vec<((String, apistos::paths::Response), Option<(String, apistos::reference_or::ReferenceOr<apistos::Schema>)>)> = vec![#(#defs,)*];
+        let responses: Vec<((String, apistos::paths::Response), Option<(String, apistos::reference_or::ReferenceOr<apistos::Schema>)>)> = vec![#defs];
         responses.into_iter().map(|v| v.0).collect()
       }
 
       fn schemas_by_status_code() -> std::collections::BTreeMap<String, (String, apistos::reference_or::ReferenceOr<apistos::Schema>)> {
         let mut schemas = std::collections::BTreeMap::default();
-        for ((status_code, _), schema) in [#(#defs,)*] {
+        for ((status_code, _), schema) in [#defs] {
           if let Some(schema) = schema {
             schemas.insert(status_code, schema);
           }
         }
         schemas
       }
-    })
+
+      fn error_child_schemas() -> Vec<(String, apistos::reference_or::ReferenceOr<apistos::Schema>)> {
+        let mut schemas = vec![];
+        #(
+          let settings = schemars::r#gen::SchemaSettings::openapi3();
+          let generator = settings.into_generator();
+          let schema: apistos::RootSchema = generator.into_root_schema_for::<#child_schema_types>();
+          schemas.extend(
+            schema
+              .definitions
+              .into_iter()
+              .map(|(name, def)| (name, apistos::reference_or::ReferenceOr::Object(def))),
+          );
+        )*
+        // schemas referenced from a oneOf of several schemas sharing the same status code
+        #(
+          let settings = schemars::r#gen::SchemaSettings::openapi3();
+          let generator = settings.into_generator();
+          let schema: apistos::RootSchema = generator.into_root_schema_for::<#referenced_schema_types>();
+          schemas.push((
+            <#referenced_schema_types as schemars::JsonSchema>::schema_name(),
+            apistos::reference_or::ReferenceOr::Object(apistos::Schema::Object(schema.schema)),
+          ));
+        )*
+        schemas
+      }
+    });
   }
 }
 
-#[derive(FromMeta, Clone)]
-pub(crate) struct ErrorDefinition {
-  pub(crate) code: u16,
-  pub(crate) description: Option<String>,
+impl ErrorResponses {
+  /// `ResponseError` implementation, only generated if requested through `response_error`.
+  pub(crate) fn response_error_impl(&self, generics: &syn::Generics) -> TokenStream {
+    if !self.response_error {
+      return quote!();
+    }
+
+    let ident = &self.ident;
+    let status_code = |code: u16| quote!(actix_web::http::StatusCode::from_u16(#code).unwrap_or(actix_web::http::StatusCode::INTERNAL_SERVER_ERROR));
+    let body = if self.variants.is_empty() {
+      self
+        .statuses
+        .first()
+        .map(|status| status_code(status.code))
+        .unwrap_or_else(|| quote!(actix_web::http::StatusCode::INTERNAL_SERVER_ERROR))
+    } else {
+      let arms = self.variants.iter().map(|(variant, code)| {
+        let status_code = status_code(*code);
+        quote!(Self::#variant { .. } => #status_code,)
+      });
+      quote! {
+        match self {
+          #(#arms)*
+          // variants without status code are internal errors
+          _ => actix_web::http::StatusCode::INTERNAL_SERVER_ERROR,
+        }
+      }
+    };
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    quote! {
+      #[automatically_derived]
+      impl #impl_generics actix_web::ResponseError for #ident #ty_generics #where_clause {
+        #[allow(unreachable_patterns)]
+        fn status_code(&self) -> actix_web::http::StatusCode {
+          #body
+        }
+      }
+    }
+  }
+}
+
+struct ErrorStatusTokens<'a> {
+  component_name: &'a str,
+  status: &'a ErrorStatus,
 }
 
-impl ToTokens for ErrorDefinition {
+impl ToTokens for ErrorStatusTokens<'_> {
   fn to_tokens(&self, tokens: &mut TokenStream) {
-    let code = self.code;
+    let code = self.status.code;
     let default_description = match StatusCode::from_u16(code) {
       Ok(status_code) => status_code.canonical_reason().unwrap_or_default(),
       Err(e) => {
@@ -64,12 +361,71 @@ impl ToTokens for ErrorDefinition {
         return;
       }
     };
-    let description = self.description.as_deref().unwrap_or(default_description);
-    tokens.extend(quote! {
+    let description = if self.status.descriptions.is_empty() {
+      default_description.to_owned()
+    } else {
+      self.status.descriptions.join("\n\n")
+    };
+    let schema = match self.status.schemas.as_slice() {
+      [] => quote!(None),
+      [schema_type] => quote! {{
+        let settings = schemars::r#gen::SchemaSettings::openapi3();
+        let generator = settings.into_generator();
+        let schema: apistos::RootSchema = generator.into_root_schema_for::<#schema_type>();
+        Some((
+          <#schema_type as schemars::JsonSchema>::schema_name(),
+          apistos::reference_or::ReferenceOr::Object(apistos::Schema::Object(schema.schema)),
+        ))
+      }},
+      schema_types => {
+        let schema_name = format!("{}{code}", self.component_name);
+        quote! {{
+          let one_of = vec![
+            #(
+              apistos::Schema::Object(apistos::SchemaObject {
+                reference: Some(format!("#/components/schemas/{}", <#schema_types as schemars::JsonSchema>::schema_name())),
+                ..Default::default()
+              }),
+            )*
+          ];
+          Some((
+            #schema_name.to_string(),
+            apistos::reference_or::ReferenceOr::Object(apistos::Schema::Object(apistos::SchemaObject {
+              subschemas: Some(Box::new(apistos::SubschemaValidation {
+                one_of: Some(one_of),
+                ..Default::default()
+              })),
+              ..Default::default()
+            })),
+          ))
+        }}
+      }
+    };
+    tokens.extend(quote! {{
+      let schema: Option<(String, apistos::reference_or::ReferenceOr<apistos::Schema>)> = #schema;
+      let content = schema
+        .as_ref()
+        .map(|(name, _)| {
+          std::collections::BTreeMap::from_iter(vec![(
+            "application/json".to_string(),
+            apistos::paths::MediaType {
+              schema: Some(apistos::reference_or::ReferenceOr::Reference {
+                _ref: format!("#/components/schemas/{name}"),
+              }),
+              ..Default::default()
+            },
+          )])
+        })
+        .unwrap_or_default();
       ((#code.to_string(), apistos::paths::Response {
         description: #description.to_string(),
+        content,
         ..Default::default()
-      }), None)
-    });
+      }), schema)
+    }});
   }
 }
+
+fn path_type(path: syn::Path) -> Type {
+  Type::Path(TypePath { qself: None, path })
+}
@@ -1,3 +1,4 @@
+use crate::internal::extensions::ExtensionAttribute;
 use darling::FromMeta;
 use proc_macro2::TokenStream;
 use quote::{ToTokens, quote};
@@ -36,6 +37,8 @@ pub(crate) struct OpenapiHeaderAttribute {
   pub(crate) description: Option<String>,
   pub(crate) required: Option<bool>,
   pub(crate) deprecated: Option<bool>,
+  #[darling(multiple, rename = "extension")]
+  pub(crate) extensions: Vec<ExtensionAttribute>,
 }
 
 impl ToTokens for OpenapiHeaderAttribute {
@@ -58,6 +61,16 @@ impl ToTokens for OpenapiHeaderAttribute {
     let required = quote!(#required);
     let deprecated = self.deprecated.unwrap_or_default();
     let deprecated = quote!(#deprecated);
+    let extensions = if self.extensions.is_empty() {
+      quote!()
+    } else {
+      let extensions = &self.extensions;
+      quote! {
+        fn extensions() -> Vec<(String, schemars::_serde_json::Value)> {
+          [#(#extensions,)*].into_iter().flatten().collect()
+        }
+      }
+    };
 
     tokens.extend(quote! {
       fn name() -> String {
@@ -75,6 +88,8 @@ impl ToTokens for OpenapiHeaderAttribute {
       fn deprecated() -> bool {
         #deprecated
       }
+
+      #extensions
     })
   }
 }
@@ -1,3 +1,4 @@
+use crate::internal::extensions::ExtensionAttribute;
 use crate::internal::security::models::{ApiKey, Http, OAuth2, OpenIdConnect};
 use darling::FromMeta;
 use proc_macro2::TokenStream;
@@ -23,6 +24,7 @@ pub(crate) fn parse_openapi_security_attrs(
       Ok(security_declaration.map(|s| SecurityDeclaration {
         name: s.name.unwrap_or(struct_name),
         scheme: s.scheme,
+        extensions: s.extensions,
       }))
     }
     Err(e) => Err(e),
@@ -33,23 +35,36 @@ pub(crate) fn parse_openapi_security_attrs(
 struct SecurityDeclarationInternal {
   name: Option<String>,
   scheme: SecurityScheme,
+  #[darling(multiple, rename = "extension")]
+  extensions: Vec<ExtensionAttribute>,
 }
 
 #[derive(FromMeta, Clone)]
 pub(crate) struct SecurityDeclaration {
   pub(crate) name: String,
   pub(crate) scheme: SecurityScheme,
+  #[darling(multiple, rename = "extension")]
+  pub(crate) extensions: Vec<ExtensionAttribute>,
 }
 
 impl ToTokens for SecurityDeclaration {
   fn to_tokens(&self, tokens: &mut TokenStream) {
     let name = &self.name;
     let scheme = &self.scheme;
+    let extensions = if self.extensions.is_empty() {
+      quote!(Default::default())
+    } else {
+      let extensions = &self.extensions;
+      quote!(apistos::IndexMap::from_iter([#(#extensions,)*].into_iter().flatten()))
+    };
     tokens.extend(quote! {
       std::collections::BTreeMap::from_iter(
         vec![(
           #name.to_string(),
-          #scheme
+          apistos::security::SecurityScheme {
+            extensions: #extensions,
+            ..#scheme
+          }
         )]
       )
     })
@@ -0,0 +1,40 @@
+use darling::FromMeta;
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+use syn::Attribute;
+
+pub(crate) fn parse_openapi_sse_event_attrs(attrs: &[Attribute]) -> darling::Result<OpenapiSseEventAttribute> {
+  let sse_event_attribute = attrs
+    .iter()
+    .filter(|attribute| attribute.path().is_ident("openapi_sse_event"))
+    .map(|attribute| OpenapiSseEventAttribute::from_meta(&attribute.meta))
+    .collect::<darling::Result<Vec<OpenapiSseEventAttribute>>>();
+
+  match sse_event_attribute {
+    Ok(sse_event_attributes) if sse_event_attributes.len() > 1 => Err(darling::Error::custom(
+      "Expected only one #[openapi_sse_event] attribute",
+    )),
+    Ok(sse_event_attributes) => Ok(sse_event_attributes.first().cloned().unwrap_or_default()),
+    Err(e) => Err(e),
+  }
+}
+
+#[derive(FromMeta, Clone, Default)]
+pub(crate) struct OpenapiSseEventAttribute {
+  #[darling(multiple, rename = "event")]
+  pub(crate) events: Vec<String>,
+}
+
+impl ToTokens for OpenapiSseEventAttribute {
+  fn to_tokens(&self, tokens: &mut TokenStream) {
+    if self.events.is_empty() {
+      return;
+    }
+    let events = &self.events;
+    tokens.extend(quote! {
+      fn event_names() -> Vec<String> {
+        vec![#(#events.to_string(),)*]
+      }
+    })
+  }
+}
@@ -1,6 +1,10 @@
+use crate::internal::examples::ExampleAttribute;
+use crate::internal::extensions::ExtensionAttribute;
+use actix_web::http::StatusCode;
 use darling::FromMeta;
 use darling::ast::NestedMeta;
-use proc_macro2::Ident;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
 use std::collections::BTreeMap;
 
 pub(crate) fn parse_openapi_operation_attrs(attrs: &[NestedMeta]) -> darling::Result<OperationAttr> {
@@ -11,6 +15,29 @@ pub(crate) fn parse_openapi_operation_attrs(attrs: &[NestedMeta]) -> darling::Re
           "`no_security` and `security_scope` cannot be used together",
         ));
       }
+      if let Some(response) = operation
+        .responses
+        .iter()
+        .chain(operation.callbacks.iter().flat_map(|callback| &callback.responses))
+        .find(|response| StatusCode::from_u16(response.code).is_err())
+      {
+        return Err(darling::Error::custom(format!(
+          "`{}` is not a valid response status code",
+          response.code
+        )));
+      }
+      if let Some(callback) = operation.callbacks.iter().find(|callback| {
+        callback
+          .method
+          .as_deref()
+          .is_some_and(|method| operation_type(method).is_none())
+      }) {
+        return Err(darling::Error::custom(format!(
+          "`{}` is not a valid method for callback `{}`",
+          callback.method.as_deref().unwrap_or_default(),
+          callback.name
+        )));
+      }
       Ok(operation.into())
     }
     Err(e) => Err(e),
@@ -34,8 +61,27 @@ struct OperationAttrInternal {
   scopes: Vec<SecurityScopes>,
   #[darling(multiple, rename = "error_code")]
   error_codes: Vec<u16>,
-  consumes: Option<String>,
-  produces: Option<String>,
+  #[darling(multiple)]
+  consumes: Vec<String>,
+  #[darling(multiple)]
+  produces: Vec<Produces>,
+  #[darling(multiple, rename = "response")]
+  responses: Vec<OperationResponse>,
+  #[darling(multiple, rename = "response_header")]
+  response_headers: Vec<ResponseHeader>,
+  #[darling(multiple, rename = "request_example")]
+  request_examples: Vec<ExampleAttribute>,
+  #[darling(multiple, rename = "response_example")]
+  response_examples: Vec<ResponseExample>,
+  #[darling(multiple, rename = "callback")]
+  callbacks: Vec<OperationCallback>,
+  #[darling(multiple, rename = "link")]
+  links: Vec<ResponseLink>,
+  #[darling(multiple, rename = "extension")]
+  extensions: Vec<ExtensionAttribute>,
+  external_docs: Option<ExternalDocs>,
+  #[darling(multiple, rename = "server")]
+  servers: Vec<OperationServer>,
   #[darling(multiple)]
   skip_args: Vec<Ident>,
 }
@@ -47,6 +93,126 @@ struct SecurityScopes {
   scopes: Vec<String>,
 }
 
+/// Content type of the success responses, either `produces = "..."` or `produces(content_type = "...", body = Type)`
+#[derive(Clone)]
+pub(crate) struct Produces {
+  pub(crate) content_type: String,
+  pub(crate) body: Option<syn::Path>,
+}
+
+#[derive(FromMeta)]
+struct ProducesInternal {
+  content_type: String,
+  body: Option<syn::Path>,
+}
+
+impl FromMeta for Produces {
+  fn from_string(value: &str) -> darling::Result<Self> {
+    Ok(Self {
+      content_type: value.to_owned(),
+      body: None,
+    })
+  }
+
+  fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+    let produces = ProducesInternal::from_list(items)?;
+    Ok(Self {
+      content_type: produces.content_type,
+      body: produces.body,
+    })
+  }
+}
+
+#[derive(FromMeta, Clone)]
+pub(crate) struct OperationResponse {
+  pub(crate) code: u16,
+  pub(crate) body: Option<syn::Path>,
+  pub(crate) description: Option<String>,
+}
+
+#[derive(FromMeta, Clone)]
+pub(crate) struct OperationCallback {
+  pub(crate) name: String,
+  pub(crate) expression: String,
+  pub(crate) method: Option<String>,
+  pub(crate) summary: Option<String>,
+  pub(crate) description: Option<String>,
+  pub(crate) body: Option<syn::Path>,
+  #[darling(multiple, rename = "response")]
+  pub(crate) responses: Vec<OperationResponse>,
+}
+
+impl OperationCallback {
+  /// Path of the `apistos::paths::OperationType` variant of this callback request, `post` by default
+  pub(crate) fn operation_type(&self) -> TokenStream {
+    let variant = operation_type(self.method.as_deref().unwrap_or("post")).unwrap_or("Post");
+    let variant = Ident::new(variant, Span::call_site());
+    quote!(apistos::paths::OperationType::#variant)
+  }
+}
+
+fn operation_type(method: &str) -> Option<&'static str> {
+  match method.to_lowercase().as_str() {
+    "get" => Some("Get"),
+    "put" => Some("Put"),
+    "post" => Some("Post"),
+    "delete" => Some("Delete"),
+    "options" => Some("Options"),
+    "head" => Some("Head"),
+    "patch" => Some("Patch"),
+    "trace" => Some("Trace"),
+    _ => None,
+  }
+}
+
+#[derive(FromMeta, Clone)]
+pub(crate) struct ResponseHeader {
+  pub(crate) name: String,
+  pub(crate) schema: syn::Path,
+  pub(crate) description: Option<String>,
+  #[darling(default)]
+  pub(crate) required: bool,
+  pub(crate) code: Option<u16>,
+}
+
+#[derive(FromMeta, Clone)]
+pub(crate) struct ResponseLink {
+  pub(crate) name: String,
+  pub(crate) operation_id: String,
+  #[darling(multiple, rename = "parameter")]
+  pub(crate) parameters: Vec<LinkParameter>,
+  pub(crate) request_body: Option<String>,
+  pub(crate) description: Option<String>,
+  pub(crate) code: Option<u16>,
+}
+
+#[derive(FromMeta, Clone)]
+pub(crate) struct LinkParameter {
+  pub(crate) name: String,
+  pub(crate) expression: String,
+}
+
+#[derive(FromMeta, Clone)]
+pub(crate) struct ExternalDocs {
+  pub(crate) url: String,
+  pub(crate) description: Option<String>,
+}
+
+#[derive(FromMeta, Clone)]
+pub(crate) struct OperationServer {
+  pub(crate) url: String,
+  pub(crate) description: Option<String>,
+}
+
+#[derive(FromMeta, Clone)]
+struct ResponseExample {
+  name: String,
+  summary: Option<String>,
+  description: Option<String>,
+  value: syn::Expr,
+  code: Option<u16>,
+}
+
 pub(crate) struct OperationAttr {
   pub(crate) skip: bool,
   pub(crate) deprecated: bool,
@@ -57,8 +223,18 @@ pub(crate) struct OperationAttr {
   pub(crate) no_security: bool,
   pub(crate) scopes: BTreeMap<String, Vec<String>>,
   pub(crate) error_codes: Vec<u16>,
-  pub(crate) consumes: Option<String>,
-  pub(crate) produces: Option<String>,
+  pub(crate) consumes: Vec<String>,
+  pub(crate) produces: Vec<Produces>,
+  pub(crate) responses: Vec<OperationResponse>,
+  pub(crate) response_headers: Vec<ResponseHeader>,
+  pub(crate) request_examples: Vec<ExampleAttribute>,
+  /// Response examples with the status code they are restricted to
+  pub(crate) response_examples: Vec<(ExampleAttribute, Option<u16>)>,
+  pub(crate) callbacks: Vec<OperationCallback>,
+  pub(crate) links: Vec<ResponseLink>,
+  pub(crate) extensions: Vec<ExtensionAttribute>,
+  pub(crate) external_docs: Option<ExternalDocs>,
+  pub(crate) servers: Vec<OperationServer>,
   pub(crate) skip_args: Vec<Ident>,
 }
 
@@ -80,6 +256,29 @@ impl From<OperationAttrInternal> for OperationAttr {
       error_codes: value.error_codes,
       consumes: value.consumes,
       produces: value.produces,
+      responses: value.responses,
+      response_headers: value.response_headers,
+      request_examples: value.request_examples,
+      response_examples: value
+        .response_examples
+        .into_iter()
+        .map(|example| {
+          (
+            ExampleAttribute {
+              name: example.name,
+              summary: example.summary,
+              description: example.description,
+              value: example.value,
+            },
+            example.code,
+          )
+        })
+        .collect(),
+      callbacks: value.callbacks,
+      links: value.links,
+      extensions: value.extensions,
+      external_docs: value.external_docs,
+      servers: value.servers,
       skip_args: value.skip_args,
     }
   }
@@ -7,16 +7,19 @@
 
 use crate::components::Components;
 use crate::info::Info;
-use crate::paths::{ExternalDocumentation, Paths};
+use crate::paths::{ExternalDocumentation, PathItem, Paths};
+use crate::reference_or::ReferenceOr;
 use crate::security::SecurityRequirement;
 use crate::server::Server;
 use crate::tag::Tag;
 use indexmap::IndexMap;
 use serde::Serialize;
 use serde_json::Value;
+use std::collections::BTreeMap;
 
 pub mod components;
 pub mod info;
+pub mod oas3_1;
 pub mod paths;
 pub mod reference_or;
 pub mod security;
@@ -25,11 +28,14 @@ pub mod tag;
 
 pub use schemars::schema::*;
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Clone, Copy, Debug)]
 #[cfg_attr(any(test, feature = "deserialize"), derive(serde::Deserialize, PartialEq))]
 pub enum OpenApiVersion {
   #[serde(rename = "3.0.3")]
   OAS3_0,
+  /// Schemas are expected to follow [JSON Schema 2020-12](https://json-schema.org/draft/2020-12/json-schema-core), see [`OpenApi::upgrade_to_oas3_1`].
+  #[serde(rename = "3.1.0")]
+  OAS3_1,
 }
 
 impl Default for OpenApiVersion {
@@ -47,10 +53,16 @@ pub struct OpenApi {
   pub openapi: OpenApiVersion,
   /// Provides metadata about the API. The metadata MAY be used by tooling as required.
   pub info: Info,
+  /// The default value for the `$schema` keyword within Schema Objects contained within this document. **Only available in OAS 3.1.**
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub json_schema_dialect: Option<String>,
   /// An array of Server Objects, which provide connectivity information to a target server. If the `servers` property is not provided, or is an empty array, the default value would be a [Server Object](https://github.com/OAI/OpenAPI-Specification/blob/main/versions/3.0.3.md#server-object) with a [url](https://github.com/OAI/OpenAPI-Specification/blob/main/versions/3.0.3.md#serverUrl) value of `/`.
   pub servers: Vec<Server>,
   /// The available paths and operations for the API.
   pub paths: Paths,
+  /// The incoming webhooks that MAY be received as part of this API and that the API consumer MAY choose to implement. **Only available in OAS 3.1.**
+  #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+  pub webhooks: BTreeMap<String, ReferenceOr<PathItem>>,
   /// An element to hold various schemas for the specification.
   #[serde(skip_serializing_if = "Option::is_none")]
   pub components: Option<Components>,
@@ -0,0 +1,331 @@
+//! Conversion of a generated document to [OAS 3.1](https://spec.openapis.org/oas/v3.1.0).
+//!
+//! Schemas are generated by schemars using its OAS 3.0 settings. OAS 3.1 relies on [JSON Schema 2020-12](https://json-schema.org/draft/2020-12/json-schema-core) instead,
+//! which means that `nullable` has to be expressed through type arrays, single valued enums through `const` and `example` through `examples`.
+
+use crate::components::Components;
+use crate::paths::{
+  Callback, Header, MediaType, Operation, Parameter, ParameterDefinition, PathItem, RequestBody, Response,
+};
+use crate::reference_or::ReferenceOr;
+use crate::{OpenApi, OpenApiVersion};
+use schemars::schema::{InstanceType, Schema, SchemaObject, SingleOrVec, SubschemaValidation};
+use schemars::visit::{Visitor, visit_schema_object};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::mem;
+
+impl OpenApi {
+  /// Upgrade the document to OAS 3.1, rewriting every schema it contains to the JSON Schema 2020-12 dialect.
+  pub fn upgrade_to_oas3_1(&mut self) {
+    self.openapi = OpenApiVersion::OAS3_1;
+
+    let visitor = &mut JsonSchema2020Visitor;
+    for path_item in self.paths.paths.values_mut() {
+      visit_path_item(visitor, path_item);
+    }
+    for webhook in self.webhooks.values_mut() {
+      if let Some(path_item) = webhook.get_object_mut() {
+        visit_path_item(visitor, path_item);
+      }
+    }
+    if let Some(components) = self.components.as_mut() {
+      visit_components(visitor, components);
+    }
+  }
+}
+
+/// Rewrites OAS 3.0 specific keywords to their JSON Schema 2020-12 equivalent.
+struct JsonSchema2020Visitor;
+
+impl Visitor for JsonSchema2020Visitor {
+  fn visit_schema_object(&mut self, schema: &mut SchemaObject) {
+    visit_schema_object(self, schema);
+
+    if let Some(example) = schema.extensions.remove("example") {
+      let metadata = schema.metadata();
+      if metadata.examples.is_empty() {
+        metadata.examples = vec![example];
+      }
+    }
+
+    if matches!(schema.extensions.remove("nullable"), Some(Value::Bool(true))) {
+      add_null_type(schema);
+    }
+
+    if schema.const_value.is_none() && schema.enum_values.as_ref().is_some_and(|values| values.len() == 1) {
+      schema.const_value = schema.enum_values.take().and_then(|values| values.into_iter().next());
+    }
+  }
+}
+
+fn add_null_type(schema: &mut SchemaObject) {
+  match schema.instance_type.take() {
+    Some(SingleOrVec::Single(instance_type)) if *instance_type == InstanceType::Null => {
+      schema.instance_type = Some(SingleOrVec::Single(instance_type));
+    }
+    Some(SingleOrVec::Single(instance_type)) => {
+      schema.instance_type = Some(SingleOrVec::Vec(vec![*instance_type, InstanceType::Null]));
+    }
+    Some(SingleOrVec::Vec(mut instance_types)) => {
+      if !instance_types.contains(&InstanceType::Null) {
+        instance_types.push(InstanceType::Null);
+      }
+      schema.instance_type = Some(SingleOrVec::Vec(instance_types));
+    }
+    None => {
+      let metadata = schema.metadata.take();
+      let extensions = mem::take(&mut schema.extensions);
+      let inner = unwrap_single_all_of(mem::take(schema));
+      *schema = SchemaObject {
+        metadata,
+        extensions,
+        subschemas: Some(Box::new(SubschemaValidation {
+          any_of: Some(vec![
+            inner,
+            Schema::Object(SchemaObject {
+              instance_type: Some(InstanceType::Null.into()),
+              ..Default::default()
+            }),
+          ]),
+          ..Default::default()
+        })),
+        ..Default::default()
+      };
+      return;
+    }
+  }
+
+  if let Some(enum_values) = schema.enum_values.as_mut() {
+    if !enum_values.contains(&Value::Null) {
+      enum_values.push(Value::Null);
+    }
+  }
+}
+
+/// schemars wraps nullable references in a single element `allOf`, which is useless once wrapped in an `anyOf`.
+fn unwrap_single_all_of(mut schema: SchemaObject) -> Schema {
+  let Some(subschemas) = schema.subschemas.take() else {
+    return Schema::Object(schema);
+  };
+  let only_all_of = SubschemaValidation {
+    all_of: None,
+    ..(*subschemas).clone()
+  } == SubschemaValidation::default();
+  match subschemas.all_of.as_deref() {
+    Some([single]) if only_all_of && schema == SchemaObject::default() => single.clone(),
+    _ => {
+      schema.subschemas = Some(subschemas);
+      Schema::Object(schema)
+    }
+  }
+}
+
+fn visit_schema<V: Visitor>(visitor: &mut V, schema: &mut ReferenceOr<Schema>) {
+  if let Some(schema) = schema.get_object_mut() {
+    visitor.visit_schema(schema);
+  }
+}
+
+fn visit_components<V: Visitor>(visitor: &mut V, components: &mut Components) {
+  for schema in components.schemas.values_mut() {
+    visit_schema(visitor, schema);
+  }
+  for response in components
+    .responses
+    .values_mut()
+    .filter_map(ReferenceOr::get_object_mut)
+  {
+    visit_response(visitor, response);
+  }
+  for parameter in components
+    .parameters
+    .values_mut()
+    .filter_map(ReferenceOr::get_object_mut)
+  {
+    visit_parameter(visitor, parameter);
+  }
+  for request_body in components
+    .request_bodies
+    .values_mut()
+    .filter_map(ReferenceOr::get_object_mut)
+  {
+    visit_request_body(visitor, request_body);
+  }
+  for header in components.headers.values_mut().filter_map(ReferenceOr::get_object_mut) {
+    visit_header(visitor, header);
+  }
+  for callback in components
+    .callbacks
+    .values_mut()
+    .filter_map(ReferenceOr::get_object_mut)
+  {
+    visit_callback(visitor, callback);
+  }
+}
+
+fn visit_path_item<V: Visitor>(visitor: &mut V, path_item: &mut PathItem) {
+  for parameter in path_item.parameters.iter_mut().filter_map(ReferenceOr::get_object_mut) {
+    visit_parameter(visitor, parameter);
+  }
+  for operation in path_item.operations.values_mut() {
+    visit_operation(visitor, operation);
+  }
+}
+
+fn visit_operation<V: Visitor>(visitor: &mut V, operation: &mut Operation) {
+  for parameter in operation.parameters.iter_mut().filter_map(ReferenceOr::get_object_mut) {
+    visit_parameter(visitor, parameter);
+  }
+  if let Some(request_body) = operation.request_body.as_mut().and_then(ReferenceOr::get_object_mut) {
+    visit_request_body(visitor, request_body);
+  }
+  let responses = operation
+    .responses
+    .default
+    .iter_mut()
+    .chain(operation.responses.responses.values_mut());
+  for response in responses.filter_map(ReferenceOr::get_object_mut) {
+    visit_response(visitor, response);
+  }
+  for callback in operation.callbacks.values_mut().filter_map(ReferenceOr::get_object_mut) {
+    visit_callback(visitor, callback);
+  }
+}
+
+fn visit_callback<V: Visitor>(visitor: &mut V, callback: &mut Callback) {
+  for path_item in callback.callbacks.values_mut() {
+    visit_path_item(visitor, path_item);
+  }
+}
+
+fn visit_parameter<V: Visitor>(visitor: &mut V, parameter: &mut Parameter) {
+  if let Some(definition) = parameter.definition.as_mut() {
+    visit_parameter_definition(visitor, definition);
+  }
+}
+
+fn visit_header<V: Visitor>(visitor: &mut V, header: &mut Header) {
+  if let Some(definition) = header.definition.as_mut() {
+    visit_parameter_definition(visitor, definition);
+  }
+}
+
+fn visit_parameter_definition<V: Visitor>(visitor: &mut V, definition: &mut ParameterDefinition) {
+  match definition {
+    ParameterDefinition::Schema(schema) => visit_schema(visitor, schema),
+    ParameterDefinition::Content(content) => visit_content(visitor, content),
+  }
+}
+
+fn visit_request_body<V: Visitor>(visitor: &mut V, request_body: &mut RequestBody) {
+  visit_content(visitor, &mut request_body.content);
+}
+
+fn visit_response<V: Visitor>(visitor: &mut V, response: &mut Response) {
+  for header in response.headers.values_mut().filter_map(ReferenceOr::get_object_mut) {
+    visit_header(visitor, header);
+  }
+  visit_content(visitor, &mut response.content);
+}
+
+fn visit_content<V: Visitor>(visitor: &mut V, content: &mut BTreeMap<String, MediaType>) {
+  for schema in content.values_mut().filter_map(|media_type| media_type.schema.as_mut()) {
+    visit_schema(visitor, schema);
+  }
+}
+
+#[cfg(test)]
+mod test {
+  #![allow(clippy::expect_used)]
+
+  use crate::OpenApi;
+  use crate::components::Components;
+  use crate::reference_or::ReferenceOr;
+  use schemars::JsonSchema;
+  use schemars::r#gen::SchemaSettings;
+  use serde_json::json;
+  use std::collections::BTreeMap;
+
+  #[allow(dead_code)]
+  #[derive(JsonSchema)]
+  struct Pet {
+    name: Option<String>,
+    kind: Kind,
+    owner: Option<Owner>,
+    #[schemars(example = "example_age")]
+    age: u8,
+  }
+
+  #[allow(dead_code)]
+  #[derive(JsonSchema)]
+  #[serde(tag = "type")]
+  enum Kind {
+    Dog,
+    Cat,
+  }
+
+  #[allow(dead_code)]
+  #[derive(JsonSchema)]
+  struct Owner {
+    name: String,
+  }
+
+  fn example_age() -> u8 {
+    3
+  }
+
+  #[test]
+  fn schemas_are_converted_to_json_schema_2020_12() {
+    let mut generator = SchemaSettings::openapi3().into_generator();
+    let root_schema = generator.root_schema_for::<Pet>();
+    let schemas = root_schema
+      .definitions
+      .into_iter()
+      .chain(vec![("Pet".to_string(), root_schema.schema.into())])
+      .map(|(name, schema)| (name, ReferenceOr::Object(schema)))
+      .collect::<BTreeMap<_, _>>();
+
+    let mut oas = OpenApi {
+      components: Some(Components {
+        schemas,
+        ..Default::default()
+      }),
+      ..Default::default()
+    };
+    oas.upgrade_to_oas3_1();
+
+    let oas_json = serde_json::to_value(&oas).expect("Error generating json for oas");
+    assert_eq!(oas_json.get("openapi"), Some(&json!("3.1.0")));
+    assert_eq!(
+      oas_json.pointer("/components/schemas/Pet/properties"),
+      Some(&json!({
+        "age": {
+          "type": "integer",
+          "format": "uint8",
+          "minimum": 0.0,
+          "examples": [3]
+        },
+        "kind": {
+          "$ref": "#/components/schemas/Kind"
+        },
+        "name": {
+          "type": ["string", "null"]
+        },
+        "owner": {
+          "anyOf": [
+            { "$ref": "#/components/schemas/Owner" },
+            { "type": "null" }
+          ]
+        }
+      }))
+    );
+    assert_eq!(
+      oas_json.pointer("/components/schemas/Kind/oneOf/0/properties/type"),
+      Some(&json!({
+        "type": "string",
+        "const": "Dog"
+      }))
+    );
+  }
+}
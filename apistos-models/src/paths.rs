@@ -32,7 +32,7 @@ pub struct PathItem {
   #[serde(flatten)]
   pub operations: IndexMap<OperationType, Operation>,
   /// An alternative `server` array to service all operations in this path.
-  #[serde(skip_serializing_if = "Vec::is_empty", default)]
+  #[serde(rename = "servers", skip_serializing_if = "Vec::is_empty", default)]
   pub server: Vec<Server>,
   /// A list of parameters that are applicable for all the operations described under this path. These parameters can be overridden at the operation level, but cannot be removed there. The list MUST NOT include duplicated parameters. A unique parameter is defined by a combination of a [name](https://github.com/OAI/OpenAPI-Specification/blob/main/versions/3.0.3.md#parameterName) and [location](https://github.com/OAI/OpenAPI-Specification/blob/main/versions/3.0.3.md#parameterIn). The list can use the [Reference Object](https://github.com/OAI/OpenAPI-Specification/blob/main/versions/3.0.3.md#reference-object) to link to parameters that are defined at the [OpenAPI Object's components/parameters](https://github.com/OAI/OpenAPI-Specification/blob/main/versions/3.0.3.md#componentsParameters).
   #[serde(skip_serializing_if = "Vec::is_empty", default)]
@@ -32,6 +32,13 @@ impl<T: Clone> ReferenceOr<T> {
     }
   }
 
+  pub fn get_object_ref(&self) -> Option<&T> {
+    match self {
+      ReferenceOr::Reference { .. } => None,
+      ReferenceOr::Object(p) => Some(p),
+    }
+  }
+
   pub fn get_object_mut(&mut self) -> Option<&mut T> {
     match self {
       ReferenceOr::Reference { .. } => None,
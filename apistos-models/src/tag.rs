@@ -20,3 +20,18 @@ pub struct Tag {
   #[serde(flatten, skip_serializing_if = "IndexMap::is_empty", skip_deserializing)]
   pub extensions: IndexMap<String, Value>,
 }
+
+impl From<&str> for Tag {
+  fn from(name: &str) -> Self {
+    Self::from(name.to_owned())
+  }
+}
+
+impl From<String> for Tag {
+  fn from(name: String) -> Self {
+    Self {
+      name,
+      ..Default::default()
+    }
+  }
+}
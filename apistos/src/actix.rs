@@ -1,15 +1,22 @@
 use crate::ApiComponent;
 use actix_web::body::BoxBody;
 use actix_web::http::StatusCode;
+use actix_web::http::header::{Accept, Header, Quality, VARY};
+use actix_web::mime::{self, Mime};
+use actix_web::web::Bytes;
 use actix_web::{HttpRequest, HttpResponse, Responder, ResponseError};
 use apistos_models::Schema;
-use apistos_models::paths::{MediaType, RequestBody, Response, Responses};
+use apistos_models::paths::{Example, Examples, MediaType, RequestBody, Response, Responses};
 use apistos_models::reference_or::ReferenceOr;
 use serde::Serialize;
 use std::collections::BTreeMap;
 use std::fmt::Debug;
 
+#[cfg(feature = "lab_sse")]
+pub use apistos_core::sse::{ApiSseEvent, TypedSse};
 pub use apistos_core::{ResponderWrapper, ResponseWrapper};
+#[cfg(feature = "lab_sse")]
+pub use apistos_gen::ApiSseEvent;
 
 /// Empty struct to represent a 204 empty response
 #[derive(Debug)]
@@ -82,13 +89,18 @@ where
     T::schema()
   }
 
+  fn examples() -> Vec<(String, Example)> {
+    T::examples()
+  }
+
   fn request_body() -> Option<RequestBody> {
     None
   }
 
   fn responses(_content_type: Option<String>) -> Option<Responses> {
     let status = StatusCode::ACCEPTED;
-    response_from_schema(status, Self::schema()).or_else(|| response_from_raw_schema(status, Self::raw_schema()))
+    response_from_schema(status, Self::schema(), Self::media_type_examples())
+      .or_else(|| response_from_raw_schema(status, Self::raw_schema(), Self::media_type_examples()))
   }
 }
 
@@ -128,13 +140,206 @@ where
     T::schema()
   }
 
+  fn examples() -> Vec<(String, Example)> {
+    T::examples()
+  }
+
   fn responses(_content_type: Option<String>) -> Option<Responses> {
     let status = StatusCode::CREATED;
-    response_from_schema(status, Self::schema()).or_else(|| response_from_raw_schema(status, Self::raw_schema()))
+    response_from_schema(status, Self::schema(), Self::media_type_examples())
+      .or_else(|| response_from_raw_schema(status, Self::raw_schema(), Self::media_type_examples()))
+  }
+}
+
+/// Representation of a [`Negotiated`] value for a content type
+type Representation<T> = Box<dyn FnOnce(&T) -> Result<Bytes, actix_web::Error>>;
+
+/// Responder serving its value in the representation best matching the request `Accept` header, with a 200 status.
+///
+/// Representations are added through [`Negotiated::with`] and [`Negotiated::with_json`], the first one being served when no `Accept` header is sent.
+/// Each representation gets the quality of the most specific range of the `Accept` header matching it, a zero quality refusing it, and requests accepting none of the representations get a `406 Not Acceptable`.
+/// Representations are expected to match the `produces` declared on the operation, responses of undocumented content types being reported by the response validation of the `validation` feature:
+///
+/// ```rust,ignore
+/// #[api_operation(produces = "application/json", produces(content_type = "text/csv", body = String))]
+/// async fn report() -> Negotiated<Report> {
+///   Negotiated::new(build_report())
+///     .with_json()
+///     .with("text/csv", |report| report.to_csv())
+/// }
+/// ```
+pub struct Negotiated<T> {
+  value: T,
+  representations: Vec<(Mime, Representation<T>)>,
+}
+
+impl<T> Negotiated<T> {
+  pub fn new(value: T) -> Self {
+    Self {
+      value,
+      representations: vec![],
+    }
+  }
+
+  /// Serve the value as **`content_type`**, the body being built by **`representation`** when this content type is selected.
+  ///
+  /// Invalid content types are ignored.
+  pub fn with<F, B>(self, content_type: &str, representation: F) -> Self
+  where
+    F: FnOnce(&T) -> B + 'static,
+    B: Into<Bytes>,
+  {
+    match content_type.parse::<Mime>() {
+      Ok(content_type) => self.push(content_type, move |value| Ok(representation(value).into())),
+      Err(_) => self,
+    }
+  }
+
+  fn push<F>(mut self, content_type: Mime, representation: F) -> Self
+  where
+    F: FnOnce(&T) -> Result<Bytes, actix_web::Error> + 'static,
+  {
+    self.representations.push((content_type, Box::new(representation)));
+    self
+  }
+
+  /// Position of the representation best matching **`accept`**, the first representations winning ties
+  fn select(&self, accept: &Accept) -> Option<usize> {
+    self
+      .representations
+      .iter()
+      .enumerate()
+      .filter_map(|(position, (content_type, _))| {
+        quality(accept, content_type)
+          .filter(|quality| *quality > Quality::ZERO)
+          .map(|quality| (position, quality))
+      })
+      .min_by(|(position, quality), (other_position, other_quality)| {
+        other_quality.cmp(quality).then(position.cmp(other_position))
+      })
+      .map(|(position, _)| position)
+  }
+}
+
+impl<T> Negotiated<T>
+where
+  T: Serialize,
+{
+  /// Serve the value as json.
+  pub fn with_json(self) -> Self {
+    self.push(mime::APPLICATION_JSON, |value| {
+      serde_json::to_vec(value).map(Bytes::from).map_err(Into::into)
+    })
+  }
+}
+
+/// Quality given to **`content_type`** by the most specific range of **`accept`** matching it
+fn quality(accept: &Accept, content_type: &Mime) -> Option<Quality> {
+  accept
+    .iter()
+    .filter_map(|accepted| {
+      let range = &accepted.item;
+      let specificity = if range.type_() == mime::STAR {
+        0
+      } else if range.type_() != content_type.type_() {
+        return None;
+      } else if range.subtype() == mime::STAR {
+        1
+      } else if range.subtype() == content_type.subtype() {
+        2
+      } else {
+        return None;
+      };
+      Some((specificity, accepted.quality))
+    })
+    .max_by_key(|(specificity, _)| *specificity)
+    .map(|(_, quality)| quality)
+}
+
+impl<T> Responder for Negotiated<T> {
+  type Body = BoxBody;
+
+  fn respond_to(mut self, req: &HttpRequest) -> HttpResponse<Self::Body> {
+    let selected = match Accept::parse(req) {
+      Ok(accept) if !accept.is_empty() => self.select(&accept),
+      _ => (!self.representations.is_empty()).then_some(0),
+    };
+    let Some(position) = selected else {
+      return HttpResponse::build(StatusCode::NOT_ACCEPTABLE).finish();
+    };
+    let (content_type, representation) = self.representations.swap_remove(position);
+    match representation(&self.value) {
+      Ok(body) => HttpResponse::build(StatusCode::OK)
+        .content_type(content_type.to_string())
+        .insert_header((VARY, "accept"))
+        .body(body),
+      Err(e) => e.error_response(),
+    }
   }
 }
 
-fn response_from_schema(status: StatusCode, schema: Option<(String, ReferenceOr<Schema>)>) -> Option<Responses> {
+impl<T> ApiComponent for Negotiated<T>
+where
+  T: ApiComponent,
+{
+  fn child_schemas() -> Vec<(String, ReferenceOr<Schema>)> {
+    T::child_schemas()
+  }
+
+  fn raw_schema() -> Option<ReferenceOr<Schema>> {
+    T::raw_schema()
+  }
+
+  fn schema() -> Option<(String, ReferenceOr<Schema>)> {
+    T::schema()
+  }
+
+  fn examples() -> Vec<(String, Example)> {
+    T::examples()
+  }
+
+  fn responses(content_type: Option<String>) -> Option<Responses> {
+    let content = Self::response_schema().map(|schema| {
+      (
+        content_type.unwrap_or_else(|| mime::APPLICATION_JSON.to_string()),
+        MediaType {
+          schema: Some(schema),
+          example: Self::media_type_examples(),
+          ..Default::default()
+        },
+      )
+    });
+    let not_acceptable = Response {
+      description: StatusCode::NOT_ACCEPTABLE
+        .canonical_reason()
+        .unwrap_or_default()
+        .to_owned(),
+      ..Default::default()
+    };
+    Some(Responses {
+      responses: BTreeMap::from_iter(vec![
+        (
+          StatusCode::OK.as_str().to_string(),
+          ReferenceOr::Object(Response {
+            content: content.into_iter().collect(),
+            ..Default::default()
+          }),
+        ),
+        (
+          StatusCode::NOT_ACCEPTABLE.as_str().to_string(),
+          ReferenceOr::Object(not_acceptable),
+        ),
+      ]),
+      ..Default::default()
+    })
+  }
+}
+
+fn response_from_schema(
+  status: StatusCode,
+  schema: Option<(String, ReferenceOr<Schema>)>,
+  examples: Option<Examples>,
+) -> Option<Responses> {
   schema.map(|(_name, schema)| match schema {
     ReferenceOr::Reference { _ref } => Responses {
       responses: BTreeMap::from_iter(vec![(status.as_str().to_string(), ReferenceOr::Reference { _ref })]),
@@ -146,6 +351,7 @@ fn response_from_schema(status: StatusCode, schema: Option<(String, ReferenceOr<
           "application/json".to_string(),
           MediaType {
             schema: Some(schema),
+            example: examples,
             ..Default::default()
           },
         )]),
@@ -159,7 +365,11 @@ fn response_from_schema(status: StatusCode, schema: Option<(String, ReferenceOr<
   })
 }
 
-fn response_from_raw_schema(status: StatusCode, raw_schema: Option<ReferenceOr<Schema>>) -> Option<Responses> {
+fn response_from_raw_schema(
+  status: StatusCode,
+  raw_schema: Option<ReferenceOr<Schema>>,
+  examples: Option<Examples>,
+) -> Option<Responses> {
   raw_schema.map(|schema| match schema {
     ReferenceOr::Reference { _ref } => Responses {
       responses: BTreeMap::from_iter(vec![(status.as_str().to_string(), ReferenceOr::Reference { _ref })]),
@@ -171,6 +381,7 @@ fn response_from_raw_schema(status: StatusCode, raw_schema: Option<ReferenceOr<S
           "application/json".to_string(),
           MediaType {
             schema: Some(schema),
+            example: examples,
             ..Default::default()
           },
         )]),
@@ -189,7 +400,7 @@ mod test {
   #![allow(clippy::expect_used)]
 
   use crate as apistos;
-  use crate::actix::{AcceptedJson, CreatedJson, NoContent};
+  use crate::actix::{AcceptedJson, CreatedJson, Negotiated, NoContent};
   use apistos_core::ApiComponent;
   use apistos_gen::ApiComponent;
   use apistos_models::paths::Response;
@@ -239,4 +450,20 @@ mod test {
     let accepted_json_response = responses.responses.get("201");
     assert!(accepted_json_response.is_some());
   }
+
+  #[test]
+  fn negotiated_generate_valid_response() {
+    #[derive(Serialize, ApiComponent, JsonSchema)]
+    struct Test {
+      test: String,
+    }
+
+    let responses = <Negotiated<Test> as ApiComponent>::responses(Some("text/csv".to_owned()));
+    assert!(responses.is_some());
+
+    let responses = responses.expect("missing responses");
+    let ok_response = responses.responses.get("200");
+    assert!(matches!(ok_response, Some(ReferenceOr::Object(obj)) if obj.content.contains_key("text/csv")));
+    assert!(responses.responses.contains_key("406"));
+  }
 }
@@ -1,27 +1,32 @@
 use crate::internal::actix::handler::OASHandler;
 use crate::internal::actix::route::{Route, RouteWrapper};
-use crate::internal::definition_holder::DefinitionHolder;
-use crate::spec::{DefaultParameters, Spec};
+use crate::internal::definition_holder::{DefinitionHolder, DocumentedPathItem, PathItemMetadata, merge_tags};
+use crate::lint::{LintError, LintIssue, LintMode, lint_with_metadata, undocumented_response_targets};
+use crate::spec::{DefaultParameters, OperationIdStrategy, Spec, TagGroup, extension, write_spec};
 use crate::web::ServiceConfig;
 use actix_service::{IntoServiceFactory, ServiceFactory, Transform};
 use actix_web::Error;
 use actix_web::body::MessageBody;
 use actix_web::dev::{HttpServiceFactory, ServiceRequest, ServiceResponse};
+use actix_web::http::header::CacheControl;
 use actix_web::web::{get, resource};
-use apistos_models::OpenApi;
 use apistos_models::components::Components;
-use apistos_models::paths::{OperationType, Parameter};
+use apistos_models::paths::{Link, OperationIdentifier, OperationType, Parameter};
 use apistos_models::reference_or::ReferenceOr;
 use apistos_models::security::SecurityRequirement;
+use apistos_models::{OpenApi, OpenApiVersion};
 use apistos_plugins::ui::{UIPluginConfig, UIPluginWrapper};
 use indexmap::IndexMap;
 use once_cell::sync::Lazy;
 use regex::Regex;
 use schemars::schema::Schema;
-use std::collections::BTreeMap;
+use serde::Serialize;
+use serde_json::{Value, json};
+use std::collections::{BTreeMap, BTreeSet};
 use std::future::Future;
+use std::path::Path;
 use std::sync::{Arc, RwLock};
-use std::{fmt, mem};
+use std::{fmt, io, mem};
 
 pub trait OpenApiWrapper<T> {
   type Wrapper;
@@ -35,6 +40,8 @@ pub struct App<T> {
   inner: Option<actix_web::App<T>>,
   default_tags: Vec<String>,
   default_parameters: Vec<DefaultParameters>,
+  operation_id_strategy: OperationIdStrategy,
+  paths_metadata: IndexMap<String, PathItemMetadata>,
 }
 
 /// Build config to pass to `build_with` function,
@@ -64,6 +71,12 @@ pub struct BuildConfig {
   ui_plugin_configs: Vec<Box<dyn UIPluginConfig>>,
   spec_path: Option<String>,
   disable_openapi_route: bool,
+  #[cfg(feature = "yaml")]
+  yaml_openapi_path: Option<String>,
+  cache_control: Option<CacheControl>,
+  #[cfg(feature = "validation")]
+  validate_examples: bool,
+  lint: Option<LintMode>,
 }
 
 impl BuildConfig {
@@ -78,6 +91,40 @@ impl BuildConfig {
     self
   }
 
+  /// Additionally expose the openapi spec serialized as yaml at **`yaml_openapi_path`** (e.g. `/openapi.yaml`).
+  ///
+  /// Regardless of this route, the yaml spec can be retrieved from the main openapi route by sending an `Accept: application/yaml` header.
+  #[cfg(feature = "yaml")]
+  pub fn with_yaml_openapi_path<T: Into<String>>(mut self, yaml_openapi_path: T) -> Self {
+    self.yaml_openapi_path = Some(yaml_openapi_path.into());
+    self
+  }
+
+  /// Set the `Cache-Control` header of the openapi routes.
+  ///
+  /// The spec is always served with a strong `ETag`, allowing clients to revalidate it through `If-None-Match`.
+  pub fn with_cache_control(mut self, cache_control: CacheControl) -> Self {
+    self.cache_control = Some(cache_control);
+    self
+  }
+
+  /// Check examples of the generated openapi spec against their schema when building the app, logging a warning per invalid example.
+  ///
+  /// This check only runs in debug builds, see [`validate_examples`](crate::validation::validate_examples) to run it from tests.
+  #[cfg(feature = "validation")]
+  pub fn with_examples_validation(mut self) -> Self {
+    self.validate_examples = true;
+    self
+  }
+
+  /// Check the consistency of the generated openapi spec when building the app, see [`lint`](crate::lint::lint) for the checks performed.
+  ///
+  /// With [`LintMode::Strict`], [`App::try_build_with`] fails when at least one issue is found.
+  pub fn with_lint(mut self, mode: LintMode) -> Self {
+    self.lint = Some(mode);
+    self
+  }
+
   /// Prevent openapi route from being exposed
   pub fn disable_openapi_route(mut self) -> Self {
     self.disable_openapi_route = true;
@@ -121,7 +168,14 @@ impl<T> OpenApiWrapper<T> for actix_web::App<T> {
       });
 
     let mut open_api_spec = OpenApi {
+      openapi: spec.openapi_version,
       info: spec.info,
+      json_schema_dialect: spec.json_schema_dialect,
+      webhooks: spec
+        .webhooks
+        .into_iter()
+        .map(|(name, path_item)| (name, ReferenceOr::Object(path_item)))
+        .collect(),
       components: if initial_security_schemes.is_empty() {
         None
       } else {
@@ -131,8 +185,14 @@ impl<T> OpenApiWrapper<T> for actix_web::App<T> {
         })
       },
       security: empty_security_requirements,
+      extensions: spec
+        .extensions
+        .into_iter()
+        .filter_map(|(name, value)| extension(name, value))
+        .collect(),
       ..Default::default()
     };
+    merge_tag_groups(&mut open_api_spec.extensions, spec.tag_groups);
     if !spec.tags.is_empty() {
       open_api_spec.tags = spec.tags;
     }
@@ -145,6 +205,8 @@ impl<T> OpenApiWrapper<T> for actix_web::App<T> {
       inner: Some(self),
       default_tags: spec.default_tags,
       default_parameters: spec.default_parameters,
+      operation_id_strategy: spec.operation_id_strategy,
+      paths_metadata: Default::default(),
     }
   }
 }
@@ -239,6 +301,8 @@ where
       inner: self.inner.take().map(|app| app.wrap(mw)),
       default_tags: self.default_tags,
       default_parameters: self.default_parameters,
+      operation_id_strategy: self.operation_id_strategy,
+      paths_metadata: self.paths_metadata,
     }
   }
 
@@ -257,17 +321,70 @@ where
       inner: self.inner.take().map(|app| app.wrap_fn(mw)),
       default_tags: self.default_tags,
       default_parameters: self.default_parameters,
+      operation_id_strategy: self.operation_id_strategy,
+      paths_metadata: self.paths_metadata,
     }
   }
 
+  /// Validate incoming requests against the generated openapi specification, rejecting invalid ones with a [RFC 7807](https://datatracker.ietf.org/doc/html/rfc7807) problem details response.
+  ///
+  /// The specification is compiled when the app is started, so documented services can be registered before or after calling this method.
+  #[cfg(feature = "validation")]
+  pub fn validate_requests<B>(
+    self,
+  ) -> App<
+    impl ServiceFactory<
+      ServiceRequest,
+      Config = (),
+      Response = ServiceResponse<actix_web::body::EitherBody<B>>,
+      Error = Error,
+      InitError = (),
+    >,
+  >
+  where
+    T: ServiceFactory<ServiceRequest, Config = (), Response = ServiceResponse<B>, Error = Error, InitError = ()>,
+    T::Service: 'static,
+    B: MessageBody + 'static,
+  {
+    let request_validation = crate::validation::RequestValidation::new(Arc::clone(&self.open_api_spec));
+    self.wrap(request_validation)
+  }
+
+  /// Check responses against the generated openapi specification: undocumented status codes, undocumented content types and json bodies not complying with their schema are reported according to **`on_violation`**.
+  ///
+  /// Json bodies being buffered to be validated, this is meant to be used in tests or debug builds.
+  #[cfg(feature = "validation")]
+  pub fn validate_responses<B>(
+    self,
+    on_violation: crate::validation::OnViolation,
+  ) -> App<
+    impl ServiceFactory<
+      ServiceRequest,
+      Config = (),
+      Response = ServiceResponse<actix_web::body::EitherBody<B>>,
+      Error = Error,
+      InitError = (),
+    >,
+  >
+  where
+    T: ServiceFactory<ServiceRequest, Config = (), Response = ServiceResponse<B>, Error = Error, InitError = ()>,
+    T::Service: 'static,
+    B: MessageBody + 'static,
+  {
+    let response_validation = crate::validation::ResponseValidation::new(Arc::clone(&self.open_api_spec), on_violation);
+    self.wrap(response_validation)
+  }
+
   /// Add a new resource at **`openapi_path`** to expose the generated openapi schema and return an [actix_web::App](https://docs.rs/actix-web/latest/actix_web/struct.App.html)
-  #[allow(clippy::unwrap_used, clippy::expect_used)]
+  #[allow(clippy::expect_used)]
   pub fn build(self, openapi_path: &str) -> actix_web::App<T> {
-    let open_api_spec = self.open_api_spec.read().unwrap().clone();
-    self
-      .inner
-      .expect("Missing app")
-      .service(resource(openapi_path).route(get().to(OASHandler::new(open_api_spec))))
+    let open_api_spec = self.spec();
+    warn_unresolved_links(&open_api_spec);
+    warn_duplicated_operation_ids(&open_api_spec);
+    warn_undocumented_response_targets(&open_api_spec, &self.paths_metadata);
+    let handler = OASHandler::new(&open_api_spec, None, None).expect("Unable to serialize openapi spec");
+    let actix_app = self.inner.expect("Missing app");
+    actix_app.service(resource(openapi_path).route(get().to(handler)))
   }
 
   /// Add a new resource at **`openapi_path`** to expose the generated openapi schema optionally exposing it through UIs and return an [actix_web::App](https://docs.rs/actix-web/latest/actix_web/struct.App.html)
@@ -290,15 +407,71 @@ where
   ///       .with(SwaggerUIConfig::new(&"/swagger")), // with swagger-ui feature enable
   ///   );
   /// ```
-  #[allow(clippy::unwrap_used, clippy::expect_used)]
+  ///
+  /// Issues reported by [`LintMode::Strict`] are logged as errors, see [`App::try_build_with`] to fail instead.
   pub fn build_with(self, openapi_path: &str, config: BuildConfig) -> actix_web::App<T> {
-    let mut actix_app = self.inner.expect("Missing app");
+    let (actix_app, issues) = self.build_checked(openapi_path, config);
+    for issue in issues {
+      log::error!("Inconsistent openapi spec: {issue}");
+    }
+    actix_app
+  }
 
-    if config.disable_openapi_route {
-      return actix_app;
+  /// Same as [`App::build_with`], failing when linting in [`LintMode::Strict`] reports issues.
+  ///
+  /// ```rust,ignore
+  /// use actix_web::App;
+  /// use apistos::app::{BuildConfig, OpenApiWrapper};
+  /// use apistos::lint::LintMode;
+  ///
+  /// let app = App::new()
+  ///   .document(todo!())
+  ///   .service(scope("/test").service(todo!()))
+  ///   .try_build_with("/openapi.json", BuildConfig::default().with_lint(LintMode::Strict))
+  ///   .expect("Inconsistent openapi spec");
+  /// ```
+  pub fn try_build_with(self, openapi_path: &str, config: BuildConfig) -> Result<actix_web::App<T>, LintError> {
+    let (actix_app, issues) = self.build_checked(openapi_path, config);
+    if issues.is_empty() {
+      Ok(actix_app)
+    } else {
+      Err(LintError { issues })
     }
+  }
 
-    let open_api_spec = self.open_api_spec.read().unwrap().clone();
+  /// Build the app as configured by **`config`**, running the checks of the generated spec whether or not it is exposed and returning the issues found in [`LintMode::Strict`].
+  #[allow(clippy::expect_used)]
+  fn build_checked(mut self, openapi_path: &str, config: BuildConfig) -> (actix_web::App<T>, Vec<LintIssue>) {
+    let mut actix_app = self.inner.take().expect("Missing app");
+
+    let open_api_spec = self.spec();
+    warn_unresolved_links(&open_api_spec);
+
+    let mut strict_issues = vec![];
+    match config.lint {
+      Some(LintMode::Warn) => {
+        for issue in lint_with_metadata(&open_api_spec, &self.paths_metadata) {
+          log::warn!("Inconsistent openapi spec: {issue}");
+        }
+      }
+      Some(LintMode::Strict) => strict_issues = lint_with_metadata(&open_api_spec, &self.paths_metadata),
+      // duplicated operation ids and undocumented response targets are part of the lint issues
+      None => {
+        warn_duplicated_operation_ids(&open_api_spec);
+        warn_undocumented_response_targets(&open_api_spec, &self.paths_metadata);
+      }
+    }
+
+    #[cfg(all(feature = "validation", debug_assertions))]
+    if config.validate_examples {
+      for violation in crate::validation::validate_examples(&open_api_spec) {
+        log::warn!("Invalid example in openapi spec at {violation}");
+      }
+    }
+
+    if config.disable_openapi_route {
+      return (actix_app, strict_issues);
+    }
 
     let spec_path = config.spec_path.as_ref().map_or(openapi_path, String::as_str);
 
@@ -306,7 +479,70 @@ where
       actix_app = actix_app.service(UIPluginWrapper::from(plugin.build(spec_path)))
     }
 
-    actix_app.service(resource(openapi_path).route(get().to(OASHandler::new(open_api_spec))))
+    #[cfg(feature = "yaml")]
+    if let Some(yaml_openapi_path) = config.yaml_openapi_path.as_deref() {
+      let handler = OASHandler::new(
+        &open_api_spec,
+        Some(crate::spec::SpecFormat::Yaml),
+        config.cache_control.clone(),
+      )
+      .expect("Unable to serialize openapi spec");
+      actix_app = actix_app.service(resource(yaml_openapi_path).route(get().to(handler)));
+    }
+
+    let handler =
+      OASHandler::new(&open_api_spec, None, config.cache_control).expect("Unable to serialize openapi spec");
+    (
+      actix_app.service(resource(openapi_path).route(get().to(handler))),
+      strict_issues,
+    )
+  }
+
+  /// Returns the generated openapi specification, as it would be exposed by [`App::build`], without building the underlying actix app.
+  ///
+  /// The specification is converted to the [`OpenApiVersion`] requested in the [`Spec`].
+  #[allow(clippy::unwrap_used)]
+  pub fn spec(&self) -> OpenApi {
+    let mut open_api_spec = self.open_api_spec.read().unwrap().clone();
+    match open_api_spec.openapi {
+      OpenApiVersion::OAS3_0 => {
+        if !open_api_spec.webhooks.is_empty() {
+          log::warn!(
+            "Webhooks are only supported starting with OAS 3.1, they will be omitted from the generated specification"
+          );
+          open_api_spec.webhooks.clear();
+        }
+        open_api_spec.json_schema_dialect = None;
+      }
+      OpenApiVersion::OAS3_1 => open_api_spec.upgrade_to_oas3_1(),
+    }
+    open_api_spec
+  }
+
+  /// Check the consistency of the generated openapi specification, see [`lint`](crate::lint::lint) for the checks performed.
+  ///
+  /// On top of the checks performed on the specification itself, security schemes given scopes through `security_scope` are checked even when no argument of the operation requires them.
+  pub fn lint(&self) -> Vec<LintIssue> {
+    lint_with_metadata(&self.spec(), &self.paths_metadata)
+  }
+
+  /// Write the generated openapi specification to **`path`**, for example from a test or a dedicated binary so that it can be committed or used for client generation.
+  ///
+  /// The format is deduced from the file extension, see [`SpecFormat::from_path`](crate::spec::SpecFormat::from_path). No server is started, the app only needs to be documented, see [the crate documentation](crate#exporting-the-specification).
+  ///
+  /// ```rust,ignore
+  /// use actix_web::App;
+  /// use apistos::app::OpenApiWrapper;
+  /// use apistos::web::scope;
+  ///
+  /// App::new()
+  ///   .document(todo!())
+  ///   .service(scope("/test").service(todo!()))
+  ///   .write_spec("openapi.json")
+  ///   .expect("Unable to write openapi specification");
+  /// ```
+  pub fn write_spec<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+    write_spec(&self.spec(), path)
   }
 
   /// Updates the underlying spec with definitions and operations from the given definition holder.
@@ -320,26 +556,43 @@ where
       .into_iter()
       .chain(components)
       .reduce(|mut acc, component| {
-        acc.schemas.extend(component.schemas);
-        acc.responses.extend(component.responses);
-        acc.security_schemes.extend(component.security_schemes);
+        merge_components(&mut acc, component);
         acc
       });
-    definition_holder.update_path_items(&mut open_api_spec.paths.paths);
+    let mut documented_paths: IndexMap<String, DocumentedPathItem> = mem::take(&mut open_api_spec.paths.paths)
+      .into_iter()
+      .map(|(path, item)| {
+        let metadata = self.paths_metadata.shift_remove(&path).unwrap_or_default();
+        (path, DocumentedPathItem { item, metadata })
+      })
+      .collect();
+    definition_holder.update_path_items(&mut documented_paths);
+    merge_tags(&mut open_api_spec.tags, definition_holder.declared_tags());
     let mut paths = IndexMap::new();
-    for (path, mut item) in mem::take(&mut open_api_spec.paths.paths) {
+    for (path, DocumentedPathItem { mut item, metadata }) in documented_paths {
       let path = if path.starts_with('/') {
         path
       } else {
         "/".to_owned() + &path
       };
 
+      let sanitized_path = sanitize_patterned_path_parameter(&path);
       item.operations.iter_mut().for_each(|(op_type, op)| {
-        let operation_id = build_operation_id(&path, *op_type);
-        op.operation_id = op.operation_id.clone().or(Some(operation_id));
+        if op.operation_id.is_none() {
+          op.operation_id = Some(match self.operation_id_strategy {
+            // kept on the raw path so that generated ids don't change
+            OperationIdStrategy::Hash => build_operation_id(&path, *op_type),
+            _ => self.operation_id_strategy.operation_id(
+              &sanitized_path,
+              *op_type,
+              op,
+              &metadata.operations.get(op_type).cloned().unwrap_or_default(),
+            ),
+          });
+        }
       });
 
-      let sanitized_path = sanitize_patterned_path_parameter(&path);
+      self.paths_metadata.insert(sanitized_path.clone(), metadata);
       paths.insert(sanitized_path, item);
     }
 
@@ -409,6 +662,128 @@ where
   }
 }
 
+/// Merge **`component`** into **`components`**.
+///
+/// Schemas and responses are identified by their name only, a warning is logged when the same name is declared with a different content, e.g. for error types sharing the same name in different modules.
+fn merge_components(components: &mut Components, component: Components) {
+  for (name, schema) in component.schemas {
+    if let Some(existing) = components.schemas.insert(name.clone(), schema) {
+      warn_component_collision("schema", &name, &existing, components.schemas.get(&name));
+    }
+  }
+  for (name, response) in component.responses {
+    if let Some(existing) = components.responses.insert(name.clone(), response) {
+      warn_component_collision("response", &name, &existing, components.responses.get(&name));
+    }
+  }
+  components.examples.extend(component.examples);
+  components.security_schemes.extend(component.security_schemes);
+}
+
+fn warn_component_collision<C: Serialize>(kind: &str, name: &str, replaced: &C, component: Option<&C>) {
+  let differs =
+    component.is_some_and(|component| serde_json::to_value(component).ok() != serde_json::to_value(replaced).ok());
+  if differs {
+    log::warn!(
+      "Component {kind} `{name}` is declared several times with different definitions, only one of them is kept"
+    );
+  }
+}
+
+/// Add **`tag_groups`** to the `x-tagGroups` extension of the spec.
+///
+/// Groups already declared through `Spec::extensions` win over groups of `Spec::tag_groups` with the same name, other groups being appended after them.
+fn merge_tag_groups(extensions: &mut IndexMap<String, Value>, tag_groups: Vec<TagGroup>) {
+  if tag_groups.is_empty() {
+    return;
+  }
+  let Value::Array(groups) = extensions
+    .entry("x-tagGroups".to_owned())
+    .or_insert_with(|| Value::Array(vec![]))
+  else {
+    log::warn!("`x-tagGroups` extension is not an array, tag groups of the spec are ignored");
+    return;
+  };
+  for tag_group in tag_groups {
+    let already_declared = groups
+      .iter()
+      .any(|group| group.get("name").and_then(Value::as_str) == Some(tag_group.name.as_str()));
+    if !already_declared {
+      groups.push(json!({ "name": tag_group.name, "tags": tag_group.tags }));
+    }
+  }
+}
+
+/// Log a warning for every response link targeting an operation id which is not part of the spec.
+///
+/// This is checked once the app is built, as linked operations may be registered after the operations linking to them.
+fn warn_unresolved_links(open_api_spec: &OpenApi) {
+  for (operation_id, link) in unresolved_links(open_api_spec) {
+    log::warn!("Link `{link}` references an unknown operation id `{operation_id}`");
+  }
+}
+
+/// Operation ids referenced from response links without matching operation, along with the link name
+fn unresolved_links(open_api_spec: &OpenApi) -> Vec<(String, String)> {
+  let operations = || {
+    open_api_spec
+      .paths
+      .paths
+      .values()
+      .flat_map(|path_item| path_item.operations.values())
+  };
+  let operation_ids = operations()
+    .filter_map(|operation| operation.operation_id.as_deref())
+    .collect::<BTreeSet<_>>();
+
+  operations()
+    .flat_map(|operation| operation.responses.responses.values())
+    .filter_map(ReferenceOr::get_object_ref)
+    .flat_map(|response| &response.links)
+    .filter_map(|(name, link)| match link {
+      ReferenceOr::Object(Link {
+        operation_identifier: Some(OperationIdentifier::OperationId(operation_id)),
+        ..
+      }) if !operation_ids.contains(operation_id.as_str()) => Some((operation_id.clone(), name.clone())),
+      _ => None,
+    })
+    .collect()
+}
+
+/// Log a warning for every status code targeted by a header, link or example which is not documented by its operation.
+fn warn_undocumented_response_targets(open_api_spec: &OpenApi, paths_metadata: &IndexMap<String, PathItemMetadata>) {
+  for issue in undocumented_response_targets(open_api_spec, paths_metadata) {
+    log::warn!("Inconsistent openapi spec: {issue}");
+  }
+}
+
+/// Log a warning for every operation id shared by several operations, which would make the spec invalid.
+fn warn_duplicated_operation_ids(open_api_spec: &OpenApi) {
+  for (operation_id, operations) in duplicated_operation_ids(open_api_spec) {
+    log::warn!(
+      "Operation id `{operation_id}` is used by several operations: {}",
+      operations.join(", ")
+    );
+  }
+}
+
+/// Operation ids used more than once, along with the `METHOD path` of the operations using them
+pub(crate) fn duplicated_operation_ids(open_api_spec: &OpenApi) -> BTreeMap<String, Vec<String>> {
+  let mut operations_by_id: BTreeMap<String, Vec<String>> = BTreeMap::new();
+  for (path, path_item) in &open_api_spec.paths.paths {
+    for (operation_type, operation) in &path_item.operations {
+      if let Some(operation_id) = &operation.operation_id {
+        operations_by_id
+          .entry(operation_id.clone())
+          .or_default()
+          .push(format!("{} {path}", format!("{operation_type:?}").to_uppercase()));
+      }
+    }
+  }
+  operations_by_id.retain(|_, operations| operations.len() > 1);
+  operations_by_id
+}
+
 #[allow(clippy::expect_used)]
 static PATH_NAME_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"\{(?<name>\S+):(.*)\}").expect("path name regex"));
 
@@ -424,7 +799,7 @@ fn sanitize_patterned_path_parameter(path: &str) -> String {
 #[allow(clippy::expect_used)]
 static PATH_RESOURCE_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"/(.*?)/\{(.*?)\}").expect("path template regex"));
 
-fn build_operation_id(path: &str, operation_type: OperationType) -> String {
+pub(crate) fn build_operation_id(path: &str, operation_type: OperationType) -> String {
   let resource = PATH_RESOURCE_REGEX
     .captures(path)
     .and_then(|c| c.get(1))
@@ -444,18 +819,27 @@ fn build_operation_id(path: &str, operation_type: OperationType) -> String {
 mod test {
   #![allow(clippy::expect_used)]
 
-  use crate::app::{BuildConfig, OpenApiWrapper, build_operation_id};
+  use crate::app::{BuildConfig, OpenApiWrapper, build_operation_id, duplicated_operation_ids, unresolved_links};
+  use crate::lint::{LintIssue, LintMode};
   use crate::spec::Spec;
+  use crate::web::redirect;
   use actix_web::App;
+  use actix_web::http::StatusCode;
+  use actix_web::http::header::{self, CacheControl, CacheDirective, HeaderValue};
   use actix_web::test::{TestRequest, call_and_read_body, call_service, init_service, try_read_body_json};
-  use apistos_models::OpenApi;
   use apistos_models::info::Info;
-  use apistos_models::paths::OperationType;
+  use apistos_models::paths::{
+    Link, Operation, OperationIdentifier, OperationType, PathItem, Paths, Response, Responses,
+  };
+  use apistos_models::reference_or::ReferenceOr;
   use apistos_models::tag::Tag;
+  use apistos_models::{OpenApi, OpenApiVersion};
   use apistos_rapidoc::RapidocConfig;
   use apistos_redoc::RedocConfig;
   use apistos_scalar::ScalarConfig;
   use apistos_swagger_ui::SwaggerUIConfig;
+  use indexmap::IndexMap;
+  use std::collections::BTreeMap;
 
   #[actix_web::test]
   async fn open_api_available() {
@@ -670,6 +1054,213 @@ mod test {
     assert_eq!(body.tags, second_tags);
   }
 
+  #[actix_web::test]
+  async fn open_api_3_1_available() {
+    let openapi_path = "/test.json";
+
+    let spec = Spec {
+      openapi_version: OpenApiVersion::OAS3_1,
+      webhooks: BTreeMap::from_iter(vec![("newPet".to_string(), PathItem::default())]),
+      ..Default::default()
+    };
+    let app = App::new().document(spec).build(openapi_path);
+    let app = init_service(app).await;
+
+    let req = TestRequest::get().uri(openapi_path).to_request();
+    let resp = call_service(&app, req).await;
+    assert!(resp.status().is_success());
+
+    let body: OpenApi = try_read_body_json(resp).await.expect("Unable to read body");
+    assert_eq!(body.openapi, OpenApiVersion::OAS3_1);
+    assert_eq!(body.webhooks.len(), 1);
+  }
+
+  #[actix_web::test]
+  async fn webhooks_omitted_from_open_api_3_0() {
+    let openapi_path = "/test.json";
+
+    let spec = Spec {
+      webhooks: BTreeMap::from_iter(vec![("newPet".to_string(), PathItem::default())]),
+      ..Default::default()
+    };
+    let app = App::new().document(spec).build(openapi_path);
+    let app = init_service(app).await;
+
+    let req = TestRequest::get().uri(openapi_path).to_request();
+    let resp = call_service(&app, req).await;
+    assert!(resp.status().is_success());
+
+    let body: OpenApi = try_read_body_json(resp).await.expect("Unable to read body");
+    assert_eq!(body, OpenApi::default());
+  }
+
+  /// Path in the temporary directory unique to this test process, so that concurrent test runs don't overwrite each other's files
+  fn temp_path(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("apistos-{}-{name}", std::process::id()))
+  }
+
+  #[test]
+  fn open_api_written_to_file() {
+    let path = temp_path("open-api-written-to-file.json");
+
+    let spec = Spec {
+      info: Info {
+        title: "A well documented API".to_string(),
+        ..Default::default()
+      },
+      ..Default::default()
+    };
+    let app = App::new().document(spec);
+    app.write_spec(&path).expect("Unable to write spec");
+
+    let written = std::fs::read(&path).expect("Unable to read spec");
+    std::fs::remove_file(&path).expect("Unable to remove spec");
+    let body: OpenApi = serde_json::from_slice(&written).expect("Unable to parse spec");
+    assert_eq!(body, app.spec());
+    assert_eq!(body.info.title, "A well documented API");
+  }
+
+  #[actix_web::test]
+  async fn open_api_served_with_etag() {
+    let openapi_path = "/test.json";
+
+    let app = App::new().document(Spec::default()).build_with(
+      openapi_path,
+      BuildConfig::default().with_cache_control(CacheControl(vec![CacheDirective::NoCache])),
+    );
+    let app = init_service(app).await;
+
+    let req = TestRequest::get().uri(openapi_path).to_request();
+    let resp = call_service(&app, req).await;
+    assert!(resp.status().is_success());
+    assert_eq!(
+      resp.headers().get(header::CACHE_CONTROL),
+      Some(&HeaderValue::from_static("no-cache"))
+    );
+    let etag = resp.headers().get(header::ETAG).cloned().expect("Missing etag");
+
+    let req = TestRequest::get()
+      .uri(openapi_path)
+      .insert_header((header::IF_NONE_MATCH, etag.clone()))
+      .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(resp.headers().get(header::ETAG), Some(&etag));
+
+    let req = TestRequest::get()
+      .uri(openapi_path)
+      .insert_header((header::IF_NONE_MATCH, "\"outdated\""))
+      .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+  }
+
+  #[cfg(feature = "gzip")]
+  #[actix_web::test]
+  async fn open_api_served_precompressed() {
+    use std::io::Read;
+
+    let openapi_path = "/test.json";
+
+    let app = App::new().document(Spec::default()).build(openapi_path);
+    let app = init_service(app).await;
+
+    let req = TestRequest::get()
+      .uri(openapi_path)
+      .insert_header((header::ACCEPT_ENCODING, "gzip"))
+      .to_request();
+    let resp = call_service(&app, req).await;
+    assert!(resp.status().is_success());
+    assert_eq!(
+      resp.headers().get(header::CONTENT_ENCODING),
+      Some(&HeaderValue::from_static("gzip"))
+    );
+
+    let body = actix_web::test::read_body(resp).await;
+    let mut decoded = String::new();
+    flate2::read::GzDecoder::new(body.as_ref())
+      .read_to_string(&mut decoded)
+      .expect("Unable to decode body");
+    let body: OpenApi = serde_json::from_str(&decoded).expect("Unable to parse body");
+    assert_eq!(body, OpenApi::default());
+  }
+
+  #[cfg(feature = "yaml")]
+  #[actix_web::test]
+  async fn open_api_available_as_yaml() {
+    let openapi_path = "/test.json";
+    let yaml_openapi_path = "/test.yaml";
+
+    let app = App::new().document(Spec::default()).build_with(
+      openapi_path,
+      BuildConfig::default().with_yaml_openapi_path(yaml_openapi_path),
+    );
+    let app = init_service(app).await;
+
+    let req = TestRequest::get().uri(yaml_openapi_path).to_request();
+    let resp = call_service(&app, req).await;
+    assert!(resp.status().is_success());
+    assert_eq!(
+      resp.headers().get(header::CONTENT_TYPE),
+      Some(&HeaderValue::from_static("application/yaml"))
+    );
+
+    let body = actix_web::test::read_body(resp).await;
+    let body = String::from_utf8(body.to_vec()).expect("Unable to convert body to string");
+    assert!(body.starts_with("openapi: 3.0.3\n"), "Body: {body}");
+  }
+
+  #[cfg(feature = "yaml")]
+  #[actix_web::test]
+  async fn open_api_negotiated_as_yaml() {
+    let openapi_path = "/test.json";
+
+    let app = App::new().document(Spec::default()).build(openapi_path);
+    let app = init_service(app).await;
+
+    let req = TestRequest::get()
+      .uri(openapi_path)
+      .insert_header(("Accept", "application/json;q=0.5, application/yaml"))
+      .to_request();
+    let body = call_and_read_body(&app, req).await;
+    let body = String::from_utf8(body.to_vec()).expect("Unable to convert body to string");
+    assert!(body.starts_with("openapi: 3.0.3\n"), "Body: {body}");
+
+    let req = TestRequest::get()
+      .uri(openapi_path)
+      .insert_header(("Accept", "application/json, application/yaml;q=0.5"))
+      .to_request();
+    let resp = call_service(&app, req).await;
+    let body: OpenApi = try_read_body_json(resp).await.expect("Unable to read body");
+    assert_eq!(body, OpenApi::default());
+  }
+
+  #[cfg(feature = "yaml")]
+  #[test]
+  fn open_api_written_to_yaml_file() {
+    let path = temp_path("open-api-written-to-yaml-file.yaml");
+
+    let app = App::new().document(Spec::default());
+    app.write_spec(&path).expect("Unable to write spec");
+
+    let written = std::fs::read_to_string(&path).expect("Unable to read spec");
+    std::fs::remove_file(&path).expect("Unable to remove spec");
+    assert!(written.starts_with("openapi: 3.0.3\n"), "Spec: {written}");
+  }
+
+  #[cfg(not(feature = "yaml"))]
+  #[test]
+  fn open_api_not_written_to_yaml_file_without_yaml_feature() {
+    let path = temp_path("open-api-not-written-to-yaml-file.yml");
+
+    let app = App::new().document(Spec::default());
+    let error = app
+      .write_spec(&path)
+      .expect_err("Yaml spec written without yaml feature");
+    assert_eq!(error.kind(), std::io::ErrorKind::Unsupported);
+    assert!(!path.exists());
+  }
+
   #[test]
   fn test_build_operation_id() {
     let operation_id = build_operation_id("/api/v1/plop/", OperationType::Get);
@@ -682,6 +1273,117 @@ mod test {
     assert_eq!(operation_id, "get_api-v1-plip-f5c9e39d7a1acb928c72745f3893bce8")
   }
 
+  #[test]
+  fn test_unresolved_links() {
+    let link = |operation_id: &str| {
+      ReferenceOr::Object(Link {
+        operation_identifier: Some(OperationIdentifier::OperationId(operation_id.to_owned())),
+        ..Default::default()
+      })
+    };
+    let response = Response {
+      links: BTreeMap::from_iter(vec![
+        ("GetPetById".to_owned(), link("get_pet")),
+        ("GetOwnerById".to_owned(), link("get_owner")),
+      ]),
+      ..Default::default()
+    };
+    let operation = |operation_id: &str| Operation {
+      operation_id: Some(operation_id.to_owned()),
+      responses: Responses {
+        responses: BTreeMap::from_iter(vec![("200".to_owned(), ReferenceOr::Object(response.clone()))]),
+        ..Default::default()
+      },
+      ..Default::default()
+    };
+    let open_api = OpenApi {
+      paths: Paths {
+        paths: IndexMap::from_iter(vec![(
+          "/pets/{id}".to_owned(),
+          PathItem {
+            operations: IndexMap::from_iter(vec![
+              (OperationType::Get, operation("get_pet")),
+              (OperationType::Put, operation("update_pet")),
+            ]),
+            ..Default::default()
+          },
+        )]),
+        ..Default::default()
+      },
+      ..Default::default()
+    };
+
+    assert_eq!(
+      unresolved_links(&open_api),
+      vec![
+        ("get_owner".to_owned(), "GetOwnerById".to_owned()),
+        ("get_owner".to_owned(), "GetOwnerById".to_owned()),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_duplicated_operation_ids() {
+    let operation = |operation_id: &str| Operation {
+      operation_id: Some(operation_id.to_owned()),
+      ..Default::default()
+    };
+    let path_item = |operations: Vec<(OperationType, Operation)>| PathItem {
+      operations: IndexMap::from_iter(operations),
+      ..Default::default()
+    };
+    let open_api = OpenApi {
+      paths: Paths {
+        paths: IndexMap::from_iter(vec![
+          (
+            "/pets/{id}".to_owned(),
+            path_item(vec![
+              (OperationType::Get, operation("get_pet")),
+              (OperationType::Put, operation("update_pet")),
+            ]),
+          ),
+          (
+            "/owners/{id}/pet".to_owned(),
+            path_item(vec![(OperationType::Get, operation("get_pet"))]),
+          ),
+        ]),
+        ..Default::default()
+      },
+      ..Default::default()
+    };
+
+    assert_eq!(
+      duplicated_operation_ids(&open_api),
+      BTreeMap::from_iter(vec![(
+        "get_pet".to_owned(),
+        vec!["GET /pets/{id}".to_owned(), "GET /owners/{id}/pet".to_owned()]
+      )])
+    );
+  }
+
+  #[test]
+  fn test_strict_lint() {
+    let result = App::new()
+      .document(Spec::default())
+      .service(redirect("/pets/{id}", "/animals"))
+      .try_build_with(
+        "/openapi.json",
+        BuildConfig::default()
+          .with_lint(LintMode::Strict)
+          .disable_openapi_route(),
+      );
+    // the redirection is registered for every method
+    let issues = result.err().map(|error| error.issues).unwrap_or_default();
+    assert_eq!(issues.len(), 7);
+    assert_eq!(
+      issues.first(),
+      Some(&LintIssue::MissingPathParameter {
+        parameter: "id".to_owned(),
+        operation: "GET /pets/{id}".to_owned(),
+      })
+    );
+  }
+
   #[actix_web::test]
   async fn test_prefixed_openapi_path() {
     let openapi_path = "/test.json";
@@ -1,21 +1,200 @@
-use actix_web::{Error, HttpResponse};
+use crate::spec::SpecFormat;
+#[cfg(feature = "yaml")]
+use actix_web::http::header::Accept;
+use actix_web::http::header::{
+  self, AcceptEncoding, CacheControl, ContentEncoding, ETag, Encoding, EntityTag, Header, HeaderValue, IfNoneMatch,
+};
+use actix_web::web::Bytes;
+use actix_web::{Error, HttpRequest, HttpResponse};
 use apistos_models::OpenApi;
 use std::future::{Ready, ready};
+use std::io;
+#[cfg(feature = "gzip")]
+use std::io::Write;
 
+/// Serves an openapi specification serialized once when building the app.
 #[derive(Clone)]
-pub(crate) struct OASHandler(OpenApi);
+pub(crate) struct OASHandler {
+  /// Serialized specifications, the first one being served by default
+  specs: Vec<SerializedSpec>,
+  /// Whether the format should be negotiated from the `Accept` header
+  negotiate_format: bool,
+  cache_control: Option<CacheControl>,
+}
+
+#[derive(Clone)]
+struct SerializedSpec {
+  format: SpecFormat,
+  etag: EntityTag,
+  /// Pre-compressed bodies followed by the identity encoded one
+  bodies: Vec<(Encoding, Bytes)>,
+}
 
 impl OASHandler {
-  pub(crate) fn new(open_api: OpenApi) -> Self {
-    Self(open_api)
+  /// Serialize the specification to the given format, or to every supported format negotiated from the `Accept` header if none.
+  pub(crate) fn new(
+    open_api: &OpenApi,
+    format: Option<SpecFormat>,
+    cache_control: Option<CacheControl>,
+  ) -> io::Result<Self> {
+    let formats = match format {
+      Some(format) => vec![format],
+      None => vec![
+        SpecFormat::Json,
+        #[cfg(feature = "yaml")]
+        SpecFormat::Yaml,
+      ],
+    };
+    let specs = formats
+      .into_iter()
+      .map(|format| SerializedSpec::new(open_api, format))
+      .collect::<io::Result<Vec<_>>>()?;
+
+    Ok(Self {
+      specs,
+      negotiate_format: format.is_none(),
+      cache_control,
+    })
+  }
+
+  #[cfg_attr(not(feature = "yaml"), allow(unused_variables))]
+  fn select_spec(&self, req: &HttpRequest) -> Option<&SerializedSpec> {
+    if !self.negotiate_format {
+      return self.specs.first();
+    }
+
+    #[cfg(feature = "yaml")]
+    if let Ok(accept) = Accept::parse(req) {
+      let preferred =
+        accept
+          .ranked()
+          .into_iter()
+          .find_map(|mime| match (mime.type_().as_str(), mime.subtype().as_str()) {
+            ("application", "json") => Some(SpecFormat::Json),
+            ("application" | "text", "yaml" | "x-yaml") => Some(SpecFormat::Yaml),
+            _ => None,
+          });
+      if let Some(spec) = preferred.and_then(|format| self.specs.iter().find(|spec| spec.format == format)) {
+        return Some(spec);
+      }
+    }
+
+    self.specs.first()
+  }
+
+  fn vary(&self) -> Option<HeaderValue> {
+    let negotiated_headers = [
+      (self.negotiate_format && self.specs.len() > 1).then_some("accept"),
+      cfg!(any(feature = "gzip", feature = "brotli")).then_some("accept-encoding"),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>();
+
+    if negotiated_headers.is_empty() {
+      None
+    } else {
+      HeaderValue::from_str(&negotiated_headers.join(", ")).ok()
+    }
   }
 }
 
-impl actix_web::Handler<()> for OASHandler {
+impl SerializedSpec {
+  fn new(open_api: &OpenApi, format: SpecFormat) -> io::Result<Self> {
+    let body = match format {
+      SpecFormat::Json => serde_json::to_vec(open_api).map_err(io::Error::other)?,
+      #[cfg(feature = "yaml")]
+      SpecFormat::Yaml => format.to_vec(open_api)?,
+    };
+    let etag = EntityTag::new_strong(format!("{:x}", md5::compute(&body)));
+
+    let bodies = vec![
+      #[cfg(feature = "brotli")]
+      (Encoding::brotli(), brotli_compress(&body)?),
+      #[cfg(feature = "gzip")]
+      (Encoding::gzip(), gzip_compress(&body)?),
+      (Encoding::identity(), Bytes::from(body)),
+    ];
+
+    Ok(Self { format, etag, bodies })
+  }
+
+  /// Pick the body best matching the `Accept-Encoding` header, falling back to the identity encoded one.
+  fn select_body(&self, req: &HttpRequest) -> Option<(EntityTag, &Encoding, &Bytes)> {
+    let encoding = AcceptEncoding::parse(req)
+      .ok()
+      .and_then(|accept_encoding| accept_encoding.negotiate(self.bodies.iter().map(|(encoding, _)| encoding)))
+      .unwrap_or_else(Encoding::identity);
+    let (encoding, body) = self
+      .bodies
+      .iter()
+      .find(|(supported, _)| *supported == encoding)
+      .or_else(|| self.bodies.last())?;
+
+    // strong entity tags have to differ between content codings
+    let etag = if *encoding == Encoding::identity() {
+      self.etag.clone()
+    } else {
+      EntityTag::new_strong(format!("{}-{encoding}", self.etag.tag()))
+    };
+    Some((etag, encoding, body))
+  }
+}
+
+impl actix_web::Handler<(HttpRequest,)> for OASHandler {
   type Output = Result<HttpResponse, Error>;
   type Future = Ready<Self::Output>;
 
-  fn call(&self, _: ()) -> Self::Future {
-    ready(Ok(HttpResponse::Ok().json(self.0.clone())))
+  fn call(&self, (req,): (HttpRequest,)) -> Self::Future {
+    let Some((spec, (etag, encoding, body))) = self
+      .select_spec(&req)
+      .and_then(|spec| spec.select_body(&req).map(|body| (spec, body)))
+    else {
+      return ready(Ok(HttpResponse::NotFound().finish()));
+    };
+
+    let not_modified = match IfNoneMatch::parse(&req) {
+      Ok(IfNoneMatch::Any) => true,
+      Ok(IfNoneMatch::Items(etags)) => etags.iter().any(|candidate| candidate.weak_eq(&etag)),
+      Err(_) => false,
+    };
+
+    let mut response = if not_modified {
+      HttpResponse::NotModified()
+    } else {
+      HttpResponse::Ok()
+    };
+    response.insert_header(ETag(etag));
+    if let Some(cache_control) = self.cache_control.clone() {
+      response.insert_header(cache_control);
+    }
+    if let Some(vary) = self.vary() {
+      response.insert_header((header::VARY, vary));
+    }
+    if not_modified {
+      return ready(Ok(response.finish()));
+    }
+
+    // an eventual compression middleware won't encode pre-compressed bodies again
+    if let Encoding::Known(content_encoding) = encoding {
+      if *content_encoding != ContentEncoding::Identity {
+        response.insert_header(*content_encoding);
+      }
+    }
+    ready(Ok(response.content_type(spec.format.content_type()).body(body.clone())))
   }
 }
+
+#[cfg(feature = "gzip")]
+fn gzip_compress(body: &[u8]) -> io::Result<Bytes> {
+  let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::best());
+  encoder.write_all(body)?;
+  encoder.finish().map(Bytes::from)
+}
+
+#[cfg(feature = "brotli")]
+fn brotli_compress(mut body: &[u8]) -> io::Result<Bytes> {
+  let mut compressed = Vec::new();
+  brotli::BrotliCompress(&mut body, &mut compressed, &brotli::enc::BrotliEncoderParams::default())?;
+  Ok(Bytes::from(compressed))
+}
@@ -1,6 +1,8 @@
 use crate::internal::actix::METHODS;
 use crate::internal::actix::route::{Route, RouteWrapper};
 use crate::internal::actix::utils::OperationUpdater;
+use crate::internal::definition_holder::{DocumentedPathItem, OperationMetadata};
+use crate::spec::extension;
 use actix_service::{ServiceFactory, Transform};
 use actix_web::body::MessageBody;
 use actix_web::dev::{AppService, HttpServiceFactory, ServiceRequest, ServiceResponse};
@@ -8,15 +10,22 @@ use actix_web::guard::Guard;
 use actix_web::{Error, FromRequest, Handler, Responder};
 use apistos_core::PathItemDefinition;
 use apistos_models::components::Components;
-use apistos_models::paths::PathItem;
+use apistos_models::server::Server;
+use apistos_models::tag::Tag;
+use indexmap::IndexMap;
+use serde_json::Value;
 use std::fmt::Debug;
 use std::future::Future;
 
 pub struct Resource<R = actix_web::Resource> {
   pub(crate) path: String,
-  pub(crate) item_definition: Option<PathItem>,
+  pub(crate) item_definition: Option<DocumentedPathItem>,
   pub(crate) components: Vec<Components>,
   tags: Vec<String>,
+  pub(crate) declared_tags: Vec<Tag>,
+  pub(crate) extensions: IndexMap<String, Value>,
+  pub(crate) servers: Vec<Server>,
+  pub(crate) name: Option<String>,
   inner: R,
 }
 
@@ -28,11 +37,15 @@ impl Resource {
       item_definition: None,
       components: Default::default(),
       tags: Default::default(),
+      declared_tags: Default::default(),
+      extensions: Default::default(),
+      servers: Default::default(),
+      name: None,
       inner: actix_web::Resource::new(path),
     }
   }
 
-  /// Wrapper for [`actix_web::Resource::new`](https://docs.rs/actix-web/*/actix_web/struct.Resource.html#method.new) with a list of tag names for the given scope.
+  /// Wrapper for [`actix_web::Resource::new`](https://docs.rs/actix-web/*/actix_web/struct.Resource.html#method.new) with a list of tag names for the given resource.
   /// Tags should exist in `Spec` otherwise documentation might be considered invalid by consumers.
   pub fn new_tagged<T: Into<String>>(path: &str, tags: Vec<T>) -> Resource {
     Resource {
@@ -40,6 +53,27 @@ impl Resource {
       item_definition: None,
       components: Default::default(),
       tags: tags.into_iter().map(Into::into).collect(),
+      declared_tags: Default::default(),
+      extensions: Default::default(),
+      servers: Default::default(),
+      name: None,
+      inner: actix_web::Resource::new(path),
+    }
+  }
+
+  /// Wrapper for [`actix_web::Resource::new`](https://docs.rs/actix-web/*/actix_web/struct.Resource.html#method.new) with a list of full tags for the given resource.
+  /// Tags are added to the tags of the spec unless a tag with the same name already exists, in which case they complete its description, external docs and extensions.
+  pub fn new_with_tags<T: IntoIterator<Item = Tag>>(path: &str, tags: T) -> Resource {
+    let declared_tags: Vec<Tag> = tags.into_iter().collect();
+    Resource {
+      path: path.to_owned(),
+      item_definition: None,
+      components: Default::default(),
+      tags: declared_tags.iter().map(|tag| tag.name.clone()).collect(),
+      declared_tags,
+      extensions: Default::default(),
+      servers: Default::default(),
+      name: None,
       inner: actix_web::Resource::new(path),
     }
   }
@@ -60,10 +94,11 @@ impl<T> Resource<actix_web::Resource<T>>
 where
   T: ServiceFactory<ServiceRequest, Config = (), Error = Error, InitError = ()>,
 {
-  /// Proxy for [`actix_web::Resource::name`](https://docs.rs/actix-web/*/actix_web/struct.Resource.html#method.name).
+  /// Wrapper for [`actix_web::Resource::name`](https://docs.rs/actix-web/*/actix_web/struct.Resource.html#method.name).
   ///
-  /// **NOTE:** This doesn't affect spec generation.
+  /// The name is used to generate `operationId` when `Spec::operation_id_strategy` is `OperationIdStrategy::ResourceName`.
   pub fn name(mut self, name: &str) -> Self {
+    self.name = Some(name.to_owned());
     self.inner = self.inner.name(name);
     self
   }
@@ -76,15 +111,29 @@ where
     self
   }
 
+  /// Add an alternative server to the path item of this resource, overriding the servers of the spec for its operations.
+  pub fn server(mut self, server: Server) -> Self {
+    self.servers.push(server);
+    self
+  }
+
+  /// Add a [specification extension](https://spec.openapis.org/oas/latest.html#specification-extensions) to the path item of this resource, e.g. `x-amazon-apigateway-any-method`.
+  ///
+  /// Extensions whose name doesn't start with `x-` are ignored, see [`extension`](crate::spec::extension).
+  pub fn extension<N: Into<String>>(mut self, name: N, value: Value) -> Self {
+    self.extensions.extend(extension(name, value));
+    self
+  }
+
   /// Wrapper for [`actix_web::Resource::route`](https://docs.rs/actix-web/*/actix_web/struct.Resource.html#method.route).
   pub fn route(mut self, route: Route) -> Self {
     let w = RouteWrapper::new(&self.path, route);
     let mut item_definition = self.item_definition.unwrap_or_default();
-    let mut operations = w.def.item.operations;
-    for operation in operations.values_mut() {
+    let mut item = w.def.item;
+    for operation in item.item.operations.values_mut() {
       operation.tags.append(&mut self.tags.clone());
     }
-    item_definition.operations.extend(operations);
+    item_definition.merge(item);
     self.item_definition = Some(item_definition);
     self.components.extend(w.component);
     self.inner = self.inner.route(w.inner);
@@ -111,9 +160,10 @@ where
     if F::Future::is_visible() {
       let mut operation = F::Future::operation();
       operation.tags.append(&mut self.tags.clone());
+      let metadata = OperationMetadata::from_definition::<F::Future>();
       let mut item_definition = self.item_definition.unwrap_or_default();
       for method in METHODS {
-        item_definition.operations.insert(*method, operation.clone());
+        item_definition.insert_operation(*method, operation.clone(), metadata.clone());
       }
       operation.update_path_parameter_name_from_path(&self.path);
       self.item_definition = Some(item_definition);
@@ -143,6 +193,10 @@ where
       item_definition: self.item_definition,
       components: self.components,
       tags: self.tags,
+      declared_tags: self.declared_tags,
+      extensions: self.extensions,
+      servers: self.servers,
+      name: self.name,
       inner: self.inner.wrap(mw),
     }
   }
@@ -168,6 +222,10 @@ where
       item_definition: self.item_definition,
       components: self.components,
       tags: self.tags,
+      declared_tags: self.declared_tags,
+      extensions: self.extensions,
+      servers: self.servers,
+      name: self.name,
       inner: self.inner.wrap_fn(mw),
     }
   }
@@ -191,8 +249,13 @@ pub fn resource(path: &str) -> Resource {
   Resource::new(path)
 }
 
-/// Wrapper for [`actix_web::web::resource`](https://docs.rs/actix-web/*/actix_web/web/fn.resource.html) with a list of tag names for the given scope.
+/// Wrapper for [`actix_web::web::resource`](https://docs.rs/actix-web/*/actix_web/web/fn.resource.html) with a list of tag names for the given resource.
 /// Tags should exist in `Spec` otherwise documentation might be considered invalid by consumers.
 pub fn tagged_resource<T: Into<String>>(path: &str, tags: Vec<T>) -> Resource {
   Resource::new_tagged(path, tags)
 }
+
+/// Wrapper for [`actix_web::web::resource`](https://docs.rs/actix-web/*/actix_web/web/fn.resource.html) with a list of full tags for the given resource, see [`Resource::new_with_tags`].
+pub fn resource_with_tags<T: IntoIterator<Item = Tag>>(path: &str, tags: T) -> Resource {
+  Resource::new_with_tags(path, tags)
+}
@@ -1,5 +1,6 @@
 use crate::internal::actix::METHODS;
 use crate::internal::actix::utils::OperationUpdater;
+use crate::internal::definition_holder::{DocumentedPathItem, OperationMetadata};
 use actix_service::boxed::BoxService;
 use actix_service::{ServiceFactory, Transform};
 use actix_web::body::MessageBody;
@@ -9,8 +10,7 @@ use actix_web::http::Method;
 use actix_web::{Error, FromRequest, Handler, Responder};
 use apistos_core::PathItemDefinition;
 use apistos_models::components::Components;
-use apistos_models::paths::{Operation, OperationType, PathItem};
-use indexmap::IndexMap;
+use apistos_models::paths::{Operation, OperationType};
 use log::warn;
 
 /// Wrapper for [`actix_web::web::method`](https://docs.rs/actix-web/*/actix_web/web/fn.method.html).
@@ -61,6 +61,7 @@ pub enum OperationTypeDoc {
 
 pub struct Route {
   operation: Option<Operation>,
+  metadata: OperationMetadata,
   path_item_type: OperationTypeDoc,
   components: Vec<Components>,
   inner: actix_web::Route,
@@ -87,6 +88,7 @@ impl Route {
   pub fn new() -> Route {
     Route {
       operation: None,
+      metadata: Default::default(),
       path_item_type: OperationTypeDoc::AllMethods,
       components: Default::default(),
       inner: actix_web::Route::new(),
@@ -109,6 +111,7 @@ impl Route {
   {
     Route {
       operation: self.operation,
+      metadata: self.metadata,
       path_item_type: self.path_item_type,
       components: self.components,
       inner: self.inner.wrap(mw),
@@ -154,6 +157,7 @@ impl Route {
   {
     if F::Future::is_visible() {
       self.operation = Some(F::Future::operation());
+      self.metadata = OperationMetadata::from_definition::<F::Future>();
       self.components = F::Future::components();
     }
     self.inner = self.inner.to(handler);
@@ -163,7 +167,7 @@ impl Route {
 
 pub(crate) struct PathDefinition {
   pub(crate) path: String,
-  pub(crate) item: PathItem,
+  pub(crate) item: DocumentedPathItem,
 }
 
 pub(crate) struct RouteWrapper {
@@ -174,25 +178,23 @@ pub(crate) struct RouteWrapper {
 
 impl RouteWrapper {
   pub(crate) fn new<S: Into<String>>(path: S, route: Route) -> Self {
-    let mut operations: IndexMap<OperationType, Operation> = Default::default();
-    let mut path_item = PathItem::default();
+    let mut path_item = DocumentedPathItem::default();
     let path: String = path.into();
     if let Some(mut operation) = route.operation {
       operation.update_path_parameter_name_from_path(&path);
 
       match route.path_item_type {
         OperationTypeDoc::OperationType(path_item_type) => {
-          operations.insert(path_item_type, operation);
+          path_item.insert_operation(path_item_type, operation, route.metadata);
         }
         OperationTypeDoc::AllMethods => {
           for path_item_type in METHODS {
-            operations.insert(*path_item_type, operation.clone());
+            path_item.insert_operation(*path_item_type, operation.clone(), route.metadata.clone());
           }
         }
         OperationTypeDoc::Undocumented => {}
       }
     }
-    path_item.operations = operations;
 
     Self {
       def: PathDefinition { path, item: path_item },
@@ -1,22 +1,28 @@
 use crate::internal::actix::route::{Route, RouteWrapper};
 use crate::internal::actix::service_config::ServiceConfig;
 use crate::internal::actix::utils::OperationUpdater;
-use crate::internal::definition_holder::DefinitionHolder;
+use crate::internal::definition_holder::{DefinitionHolder, DocumentedPathItem, merge_path_item, merge_tags};
+use crate::spec::extension;
 use actix_service::{ServiceFactory, Transform};
 use actix_web::Error;
 use actix_web::body::MessageBody;
 use actix_web::dev::{AppService, HttpServiceFactory, ServiceRequest, ServiceResponse};
 use actix_web::guard::Guard;
 use apistos_models::components::Components;
-use apistos_models::paths::PathItem;
+use apistos_models::server::Server;
+use apistos_models::tag::Tag;
 use indexmap::IndexMap;
+use serde_json::Value;
 use std::fmt::Debug;
 use std::future::Future;
 
 pub struct Scope<S = actix_web::Scope> {
-  pub(crate) item_map: IndexMap<String, PathItem>,
+  pub(crate) item_map: IndexMap<String, DocumentedPathItem>,
   pub(crate) components: Vec<Components>,
   tags: Vec<String>,
+  pub(crate) declared_tags: Vec<Tag>,
+  pub(crate) extensions: IndexMap<String, Value>,
+  pub(crate) servers: Vec<Server>,
   path: String,
   inner: Option<S>,
 }
@@ -28,6 +34,9 @@ impl Scope {
       item_map: Default::default(),
       components: Default::default(),
       tags: Default::default(),
+      declared_tags: Default::default(),
+      extensions: Default::default(),
+      servers: Default::default(),
       path: path.into(),
       inner: Some(actix_web::Scope::new(path)),
     }
@@ -40,6 +49,25 @@ impl Scope {
       item_map: Default::default(),
       components: Default::default(),
       tags: tags.into_iter().map(Into::into).collect(),
+      declared_tags: Default::default(),
+      extensions: Default::default(),
+      servers: Default::default(),
+      path: path.into(),
+      inner: Some(actix_web::Scope::new(path)),
+    }
+  }
+
+  /// Wrapper for [`actix_web::Scope::new`](https://docs.rs/actix-web/*/actix_web/struct.Scope.html#method.new) with a list of full tags for the given scope.
+  /// Tags are added to the tags of the spec unless a tag with the same name already exists, in which case they complete its description, external docs and extensions.
+  pub fn new_with_tags<T: IntoIterator<Item = Tag>>(path: &str, tags: T) -> Self {
+    let declared_tags: Vec<Tag> = tags.into_iter().collect();
+    Scope {
+      item_map: Default::default(),
+      components: Default::default(),
+      tags: declared_tags.iter().map(|tag| tag.name.clone()).collect(),
+      declared_tags,
+      extensions: Default::default(),
+      servers: Default::default(),
       path: path.into(),
       inner: Some(actix_web::Scope::new(path)),
     }
@@ -79,6 +107,20 @@ where
     self
   }
 
+  /// Add an alternative server to every path item of this scope, overriding the servers of the spec for its operations.
+  pub fn server(mut self, server: Server) -> Self {
+    self.servers.push(server);
+    self
+  }
+
+  /// Add a [specification extension](https://spec.openapis.org/oas/latest.html#specification-extensions) to every path item of this scope, e.g. `x-amazon-apigateway-integration`.
+  ///
+  /// Extensions whose name doesn't start with `x-` are ignored, see [`extension`](crate::spec::extension).
+  pub fn extension<N: Into<String>>(mut self, name: N, value: Value) -> Self {
+    self.extensions.extend(extension(name, value));
+    self
+  }
+
   /// Wrapper for [`actix_web::Scope::configure`](https://docs.rs/actix-web/*/actix_web/struct.Scope.html#method.configure).
   pub fn configure<F>(mut self, f: F) -> Self
   where
@@ -144,6 +186,9 @@ where
       item_map: self.item_map,
       components: self.components,
       tags: self.tags,
+      declared_tags: self.declared_tags,
+      extensions: self.extensions,
+      servers: self.servers,
       path: self.path,
       inner: self.inner.take().map(|s| s.wrap(mw)),
     }
@@ -168,16 +213,20 @@ where
       item_map: self.item_map,
       components: self.components,
       tags: self.tags,
+      declared_tags: self.declared_tags,
+      extensions: self.extensions,
+      servers: self.servers,
       path: self.path,
       inner: self.inner.take().map(|s| s.wrap_fn(mw)),
     }
   }
 
   fn update_from_def_holder<D: DefinitionHolder>(&mut self, dh: &mut D) {
-    let mut item_map: IndexMap<String, PathItem> = IndexMap::new();
+    let mut item_map: IndexMap<String, DocumentedPathItem> = IndexMap::new();
     dh.update_path_items(&mut item_map);
 
     self.components.extend(dh.components());
+    merge_tags(&mut self.declared_tags, dh.declared_tags());
 
     for (path, mut path_item) in item_map {
       let p = [self.path.clone(), path]
@@ -187,14 +236,12 @@ where
         .collect::<Vec<&str>>()
         .join("/");
 
-      for operation in path_item.operations.values_mut() {
+      for operation in path_item.item.operations.values_mut() {
         operation.update_path_parameter_name_from_path(&p);
         operation.tags.append(&mut self.tags.clone());
       }
 
-      let op_map = self.item_map.entry(p).or_default();
-      //@todo we should probably merge the path items together instead but for now only operations can be defined using apistos here
-      op_map.operations.extend(path_item.operations);
+      merge_path_item(&mut self.item_map, p, path_item);
     }
   }
 }
@@ -209,3 +256,8 @@ pub fn scope(path: &str) -> Scope {
 pub fn tagged_scope<T: Into<String>>(path: &str, tags: Vec<T>) -> Scope {
   Scope::new_tagged(path, tags)
 }
+
+/// Wrapper for [`actix_web::web::scope`](https://docs.rs/actix-web/*/actix_web/web/fn.scope.html) with a list of full tags for the given scope, see [`Scope::new_with_tags`].
+pub fn scope_with_tags<T: IntoIterator<Item = Tag>>(path: &str, tags: T) -> Scope {
+  Scope::new_with_tags(path, tags)
+}
@@ -1,13 +1,14 @@
 use crate::internal::actix::route::{Route, RouteWrapper};
-use crate::internal::definition_holder::DefinitionHolder;
+use crate::internal::definition_holder::{DefinitionHolder, DocumentedPathItem, merge_tags};
 use actix_web::dev::HttpServiceFactory;
 use apistos_models::components::Components;
-use apistos_models::paths::PathItem;
+use apistos_models::tag::Tag;
 use indexmap::IndexMap;
 
 pub struct ServiceConfig<'a> {
-  pub(crate) item_map: IndexMap<String, PathItem>,
+  pub(crate) item_map: IndexMap<String, DocumentedPathItem>,
   pub(crate) components: Vec<Components>,
+  pub(crate) declared_tags: Vec<Tag>,
   inner: &'a mut actix_web::web::ServiceConfig,
 }
 
@@ -16,6 +17,7 @@ impl<'a> From<&'a mut actix_web::web::ServiceConfig> for ServiceConfig<'a> {
     ServiceConfig {
       item_map: Default::default(),
       components: Default::default(),
+      declared_tags: Default::default(),
       inner: cfg,
     }
   }
@@ -47,6 +49,7 @@ impl ServiceConfig<'_> {
   {
     factory.update_path_items(&mut self.item_map);
     self.components.extend(factory.components());
+    merge_tags(&mut self.declared_tags, factory.declared_tags());
     self.inner.service(factory);
     self
   }
@@ -4,24 +4,134 @@ use crate::internal::actix::route::RouteWrapper;
 use crate::internal::actix::scope::Scope;
 use crate::internal::actix::service_config::ServiceConfig;
 use actix_web::http::StatusCode;
+use apistos_core::PathItemDefinition;
 use apistos_models::components::Components;
 use apistos_models::paths::{Operation, OperationType, PathItem, Responses};
 use apistos_models::reference_or::ReferenceOr;
+use apistos_models::tag::Tag;
 use indexmap::IndexMap;
 use std::collections::BTreeMap;
 use std::mem;
 
 use super::actix::METHODS;
 
+/// Information gathered while documenting an operation which is not part of the openapi specification.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct OperationMetadata {
+  /// Name of the documented handler, used by `OperationIdStrategy::HandlerName`
+  pub(crate) handler_name: Option<String>,
+  /// Name given through `Resource::name`, used by `OperationIdStrategy::ResourceName`
+  pub(crate) resource_name: Option<String>,
+  /// Security schemes given scopes through `security_scope`, checked when linting the specification
+  pub(crate) security_scope_names: Vec<String>,
+  /// Status codes targeted by `response_header`, `link` or `response_example`, checked when building the specification
+  pub(crate) response_target_codes: Vec<u16>,
+}
+
+impl OperationMetadata {
+  /// Metadata of the operation documented by **`D`**.
+  pub(crate) fn from_definition<D: PathItemDefinition>() -> Self {
+    Self {
+      handler_name: D::handler_name().map(ToOwned::to_owned),
+      resource_name: None,
+      security_scope_names: D::security_scope_names(),
+      response_target_codes: D::response_target_codes(),
+    }
+  }
+}
+
+/// [`OperationMetadata`] of the operations of a path item.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct PathItemMetadata {
+  pub(crate) operations: IndexMap<OperationType, OperationMetadata>,
+  /// Methods registered more than once on this path, only the last registration being documented
+  pub(crate) duplicated_operations: Vec<OperationType>,
+}
+
+/// A path item along with its [`PathItemMetadata`].
+#[derive(Clone, Debug, Default)]
+pub struct DocumentedPathItem {
+  pub(crate) item: PathItem,
+  pub(crate) metadata: PathItemMetadata,
+}
+
+impl DocumentedPathItem {
+  /// Document **`operation`** for **`operation_type`**, keeping track of methods which were already documented.
+  pub(crate) fn insert_operation(
+    &mut self,
+    operation_type: OperationType,
+    operation: Operation,
+    metadata: OperationMetadata,
+  ) {
+    if self.item.operations.insert(operation_type, operation).is_some() {
+      self.metadata.duplicated_operations.push(operation_type);
+    }
+    self.metadata.operations.insert(operation_type, metadata);
+  }
+
+  /// Merge the operations, extensions and servers of **`other`** in this path item.
+  pub(crate) fn merge(&mut self, mut other: DocumentedPathItem) {
+    for (operation_type, operation) in other.item.operations {
+      let metadata = other
+        .metadata
+        .operations
+        .shift_remove(&operation_type)
+        .unwrap_or_default();
+      self.insert_operation(operation_type, operation, metadata);
+    }
+    self
+      .metadata
+      .duplicated_operations
+      .extend(other.metadata.duplicated_operations);
+    self.item.extensions.extend(other.item.extensions);
+    for server in other.item.server {
+      if !self.item.server.iter().any(|s| s.url == server.url) {
+        self.item.server.push(server);
+      }
+    }
+  }
+}
+
 pub trait DefinitionHolder {
   fn path(&self) -> &str;
   fn operations(&mut self) -> IndexMap<OperationType, Operation>;
   fn components(&mut self) -> Vec<Components>;
-  fn update_path_items(&mut self, path_op_map: &mut IndexMap<String, PathItem>) {
+  /// Tags declared through `scope_with_tags`, `resource_with_tags` and similar, to be added to the tags of the spec.
+  fn declared_tags(&mut self) -> Vec<Tag> {
+    vec![]
+  }
+  fn update_path_items(&mut self, path_op_map: &mut IndexMap<String, DocumentedPathItem>) {
     let ops = self.operations();
     if !ops.is_empty() {
-      let op_map = path_op_map.entry(self.path().into()).or_default();
-      op_map.operations.extend(ops);
+      let path_item = path_op_map.entry(self.path().into()).or_default();
+      for (operation_type, operation) in ops {
+        path_item.insert_operation(operation_type, operation, OperationMetadata::default());
+      }
+    }
+  }
+}
+
+/// Merge **`item`** in the path item documented at **`path`**.
+pub(crate) fn merge_path_item(
+  path_op_map: &mut IndexMap<String, DocumentedPathItem>,
+  path: String,
+  item: DocumentedPathItem,
+) {
+  path_op_map.entry(path).or_default().merge(item);
+}
+
+/// Add **`new_tags`** to **`tags`**, tags already declared with the same name being completed with the description, external docs and extensions of the new ones.
+pub(crate) fn merge_tags(tags: &mut Vec<Tag>, new_tags: Vec<Tag>) {
+  for new_tag in new_tags {
+    match tags.iter_mut().find(|tag| tag.name == new_tag.name) {
+      Some(tag) => {
+        tag.description = tag.description.take().or(new_tag.description);
+        tag.external_docs = tag.external_docs.take().or(new_tag.external_docs);
+        for (name, value) in new_tag.extensions {
+          tag.extensions.entry(name).or_insert(value);
+        }
+      }
+      None => tags.push(new_tag),
     }
   }
 }
@@ -32,12 +142,19 @@ impl DefinitionHolder for RouteWrapper {
   }
 
   fn operations(&mut self) -> IndexMap<OperationType, Operation> {
-    mem::take(&mut self.def.item.operations)
+    mem::take(&mut self.def.item.item.operations)
   }
 
   fn components(&mut self) -> Vec<Components> {
     mem::take(&mut self.component)
   }
+
+  fn update_path_items(&mut self, path_op_map: &mut IndexMap<String, DocumentedPathItem>) {
+    let item = mem::take(&mut self.def.item);
+    if !item.item.operations.is_empty() {
+      merge_path_item(path_op_map, self.def.path.clone(), item);
+    }
+  }
 }
 
 impl<T> DefinitionHolder for Resource<T> {
@@ -46,12 +163,32 @@ impl<T> DefinitionHolder for Resource<T> {
   }
 
   fn operations(&mut self) -> IndexMap<OperationType, Operation> {
-    mem::take(&mut self.item_definition).unwrap_or_default().operations
+    mem::take(&mut self.item_definition).unwrap_or_default().item.operations
   }
 
   fn components(&mut self) -> Vec<Components> {
     mem::take(&mut self.components)
   }
+
+  fn declared_tags(&mut self) -> Vec<Tag> {
+    mem::take(&mut self.declared_tags)
+  }
+
+  fn update_path_items(&mut self, path_op_map: &mut IndexMap<String, DocumentedPathItem>) {
+    let Some(mut item) = self.item_definition.take() else {
+      return;
+    };
+    if !item.item.operations.is_empty() {
+      item.item.extensions.extend(mem::take(&mut self.extensions));
+      item.item.server.append(&mut self.servers);
+      if let Some(name) = &self.name {
+        for metadata in item.metadata.operations.values_mut() {
+          metadata.resource_name.get_or_insert_with(|| name.clone());
+        }
+      }
+      merge_path_item(path_op_map, self.path.clone(), item);
+    }
+  }
 }
 
 #[allow(clippy::unimplemented)]
@@ -68,10 +205,15 @@ impl<T> DefinitionHolder for Scope<T> {
     mem::take(&mut self.components)
   }
 
-  fn update_path_items(&mut self, path_op_map: &mut IndexMap<String, PathItem>) {
-    for (path, item) in mem::take(&mut self.item_map) {
-      let op_map = path_op_map.entry(path).or_default();
-      op_map.operations.extend(item.operations.into_iter());
+  fn declared_tags(&mut self) -> Vec<Tag> {
+    mem::take(&mut self.declared_tags)
+  }
+
+  fn update_path_items(&mut self, path_op_map: &mut IndexMap<String, DocumentedPathItem>) {
+    for (path, mut item) in mem::take(&mut self.item_map) {
+      item.item.extensions.extend(self.extensions.clone());
+      item.item.server.extend(self.servers.clone());
+      merge_path_item(path_op_map, path, item);
     }
   }
 }
@@ -90,10 +232,13 @@ impl DefinitionHolder for ServiceConfig<'_> {
     mem::take(&mut self.components)
   }
 
-  fn update_path_items(&mut self, path_op_map: &mut IndexMap<String, PathItem>) {
+  fn declared_tags(&mut self) -> Vec<Tag> {
+    mem::take(&mut self.declared_tags)
+  }
+
+  fn update_path_items(&mut self, path_op_map: &mut IndexMap<String, DocumentedPathItem>) {
     for (path, item) in mem::take(&mut self.item_map) {
-      let op_map = path_op_map.entry(path).or_default();
-      op_map.operations.extend(item.operations.into_iter());
+      merge_path_item(path_op_map, path, item);
     }
   }
 }
@@ -115,6 +115,49 @@
 //!
 //! For a complete example, see [the sample petstore](https://github.com/netwo-io/apistos/tree/main/examples/petstore).
 //!
+//! # Exporting the specification
+//!
+//! The specification only depends on how the app is documented, it can be written to a file without starting a server, e.g. from a dedicated binary or a test, to be committed or used for client generation.
+//! Sharing the routes between the server and the export through [`App::configure`](app::App::configure) keeps both in sync:
+//!
+//! ```rust,ignore
+//! // src/bin/openapi.rs
+//! use actix_web::App;
+//! use apistos::app::OpenApiWrapper;
+//!
+//! fn main() -> std::io::Result<()> {
+//!   App::new()
+//!     .document(my_api::spec())
+//!     .configure(my_api::routes)
+//!     .write_spec("openapi.yaml") // yaml requires the `yaml` feature
+//! }
+//! ```
+//!
+//! A specification built or modified by other means can be written with [`spec::write_spec`].
+//!
+//! # Server-sent events
+//!
+//! With the `lab_sse` feature, handlers returning `apistos::actix::TypedSse<T, S>` are documented as a `200` response of content type `text/event-stream`, the schema of the payload **`T`** being the schema of every event.
+//! OpenAPI has no way to describe the names of the events, they are exposed through the `x-event-names` extension of the media type, listing the `event:` fields sent with this payload:
+//!
+//! ```rust,ignore
+//! #[derive(Serialize, Debug, Clone, JsonSchema, ApiComponent, ApiSseEvent)]
+//! #[openapi_sse_event(event = "created", event = "updated")]
+//! pub struct PetUpdate {
+//!   pub id: u32,
+//! }
+//! ```
+//!
+//! ```yaml
+//! content:
+//!   text/event-stream:
+//!     schema:
+//!       $ref: "#/components/schemas/PetUpdate"
+//!     x-event-names: [created, updated]
+//! ```
+//!
+//! The extension is omitted for payloads sent as unnamed (`message`) events.
+//!
 //! # Feature flags
 //!
 //! | name           | description                                                                 | extra dependencies                                             |
@@ -122,12 +165,17 @@
 //! | `query` (default) | Enables documenting `actix_web::web::Query`                              |                                                                |
 //! | `actix` (default) | Enables documenting types from `actix`                                   |                                                                |
 //! | `lab_query`       | Enables documenting `actix_web_lab::extract::Query`                      | [`actix-web-lab`](https://crates.io/crates/actix-web-lab)      |
+//! | `lab_sse`         | Enables documenting `actix_web_lab::sse::Sse` through `TypedSse`         | [`actix-web-lab`](https://crates.io/crates/actix-web-lab)      |
 //! | `garde`           | Enables input validation through `garde`                                 | [`garde`](https://crates.io/crates/garde)                      |
 //! | `actix-web-grants`| Enables support for `actix-web-grants`                                   | [`actix-web-grants`](https://crates.io/crates/actix-web-grants)|
 //! | `qs_query`        | Enables documenting types from `serde_qs`                                | [`serde_qs`](https://crates.io/crates/serde-qs)                |
 //! | `rapidoc`         | Enables `RapiDoc` to expose the generated openapi file                   |                                                                |
 //! | `redoc`           | Enables `ReDoc` to expose the generated openapi file                     |                                                                |
 //! | `swagger-ui`      | Enables Swagger UI to expose the generated openapi file                  |                                                                |
+//! | `yaml`            | Enables serializing the generated openapi file as yaml                   | [`serde_yaml_ng`](https://crates.io/crates/serde_yaml_ng)      |
+//! | `gzip`            | Enables serving a gzip pre-compressed openapi file                       | [`flate2`](https://crates.io/crates/flate2)                    |
+//! | `brotli`          | Enables serving a brotli pre-compressed openapi file                     | [`brotli`](https://crates.io/crates/brotli)                    |
+//! | `validation`      | Enables validating requests, responses and examples against the spec     | [`jsonschema`](https://crates.io/crates/jsonschema)            |
 //! | `chrono`          | Enables documenting types from `chrono`                                  | [`chrono`](https://crates.io/crates/chrono)                    |
 //! | `multipart`       | Enables documenting types from `actix-multipart`                         | [`actix-multipart`](https://crates.io/crates/actix-multipart)  |
 //! | `rust_decimal`    | Enables documenting types from `rust_decimal`                            | [`rust_decimal`](https://crates.io/crates/rust-decimal)        |
@@ -137,9 +185,6 @@
 //!
 //! It is possible to completely disable the documentation of `actix_web::web::Query`. This is useful when you want to enforce the use of `serde_qs::actix::QsQuery` in your project. To do so disable the default features. (Note: you might need to add `actix` feature as well)
 //!
-//! # What's next
-//! - Handle schema for errors using `ApiErrorComponent`[ derive macro
-//!
 //! # Alternatives
 //!
 //! | Crate                                             | Key differences                                                                                                                                                                                                                                                                                                                               |
@@ -153,7 +198,9 @@ pub use indexmap::IndexMap;
 pub use apistos_core::PathItemDefinition;
 pub use apistos_core::parameters::header::ApiHeader;
 pub use apistos_core::{ApiComponent, ApiErrorComponent, TypedSchema};
-pub use apistos_gen::{ApiComponent, ApiCookie, ApiErrorComponent, ApiHeader, ApiSecurity, ApiType, api_operation};
+pub use apistos_gen::{
+  ApiComponent, ApiCookie, ApiErrorComponent, ApiHeader, ApiRequest, ApiResponse, ApiSecurity, ApiType, api_operation,
+};
 pub use apistos_models::*;
 #[cfg(feature = "rapidoc")]
 pub use apistos_rapidoc::RapidocConfig;
@@ -170,7 +217,10 @@ mod internal;
 pub mod actix;
 
 pub mod app;
+pub mod lint;
 pub mod spec;
+#[cfg(feature = "validation")]
+pub mod validation;
 pub mod web;
 
 #[cfg(test)]
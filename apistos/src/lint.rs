@@ -0,0 +1,357 @@
+//! Consistency checks of the generated openapi specification.
+//!
+//! Issues can be retrieved through [`lint`] or reported when building the app through [`BuildConfig::with_lint`](crate::app::BuildConfig::with_lint).
+
+use crate::app::duplicated_operation_ids;
+use crate::internal::definition_holder::PathItemMetadata;
+use apistos_models::OpenApi;
+use apistos_models::paths::{Operation, OperationType, Parameter, ParameterIn, PathItem};
+use apistos_models::reference_or::ReferenceOr;
+use indexmap::IndexMap;
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::fmt::{Display, Formatter};
+
+/// How lint issues are reported when building the app.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LintMode {
+  /// Log a warning per issue
+  #[default]
+  Warn,
+  /// Fail [`App::try_build_with`](crate::app::App::try_build_with) when at least one issue is found, [`App::build_with`](crate::app::App::build_with) logging an error per issue
+  Strict,
+}
+
+/// An inconsistency found in the openapi specification.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LintIssue {
+  /// A `$ref` targeting a definition which is not part of the document
+  DanglingReference {
+    /// [JSON pointer](https://datatracker.ietf.org/doc/html/rfc6901) to the reference in the openapi document
+    location: String,
+    reference: String,
+  },
+  /// An operation id used by several operations, identified as `METHOD path`
+  DuplicatedOperationId {
+    operation_id: String,
+    operations: Vec<String>,
+  },
+  /// A tag used by an operation but missing from the tags of the specification
+  UndeclaredTag { tag: String, operation: String },
+  /// A security requirement or a `security_scope` referencing a scheme missing from `components.securitySchemes`
+  UndeclaredSecurityScheme { name: String, operation: Option<String> },
+  /// A path template parameter without matching `path` parameter
+  MissingPathParameter { parameter: String, operation: String },
+  /// A `path` parameter without matching path template parameter
+  UnknownPathParameter { parameter: String, operation: String },
+  /// The same method and path registered more than once, only the last registration being documented.
+  ///
+  /// Only reported by [`App::lint`](crate::app::App::lint), as the document itself only keeps the last registration.
+  DuplicatedOperation { operation: String },
+  /// A status code targeted by a `response_header`, `link` or `response_example` the operation doesn't document, the attribute being ignored.
+  ///
+  /// Also logged when building the app without linting.
+  UndocumentedResponseTarget { status: u16, operation: String },
+}
+
+impl Display for LintIssue {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::DanglingReference { location, reference } => write!(f, "{location}: unknown reference `{reference}`"),
+      Self::DuplicatedOperationId {
+        operation_id,
+        operations,
+      } => write!(
+        f,
+        "operation id `{operation_id}` is used by several operations: {}",
+        operations.join(", ")
+      ),
+      Self::UndeclaredTag { tag, operation } => write!(f, "{operation}: tag `{tag}` is not declared in the spec"),
+      Self::UndeclaredSecurityScheme {
+        name,
+        operation: Some(operation),
+      } => write!(f, "{operation}: security scheme `{name}` is not declared in components"),
+      Self::UndeclaredSecurityScheme { name, operation: None } => {
+        write!(f, "security scheme `{name}` is not declared in components")
+      }
+      Self::MissingPathParameter { parameter, operation } => {
+        write!(f, "{operation}: path parameter `{parameter}` is not documented")
+      }
+      Self::UnknownPathParameter { parameter, operation } => {
+        write!(f, "{operation}: path parameter `{parameter}` is not part of the path")
+      }
+      Self::DuplicatedOperation { operation } => {
+        write!(
+          f,
+          "{operation}: registered more than once, only the last registration is documented"
+        )
+      }
+      Self::UndocumentedResponseTarget { status, operation } => {
+        write!(
+          f,
+          "{operation}: response `{status}` is targeted by a header, link or example but is not documented"
+        )
+      }
+    }
+  }
+}
+
+/// Issues found when building the app with [`LintMode::Strict`], see [`App::try_build_with`](crate::app::App::try_build_with).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LintError {
+  pub issues: Vec<LintIssue>,
+}
+
+impl Display for LintError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "Inconsistent openapi spec:")?;
+    for issue in &self.issues {
+      write!(f, "\n{issue}")?;
+    }
+    Ok(())
+  }
+}
+
+impl std::error::Error for LintError {}
+
+/// Check the consistency of the openapi document, returning the issues found.
+///
+/// Checks relying on how the app was documented, such as `security_scope` declarations not required by any argument or methods registered more than once, are only performed by [`App::lint`](crate::app::App::lint).
+///
+/// ```rust,ignore
+/// use apistos::lint::lint;
+///
+/// #[test]
+/// fn spec_is_consistent() {
+///   let spec = App::new()
+///     .document(spec())
+///     .service(scope("/test").service(todo!()))
+///     .spec();
+///   assert_eq!(lint(&spec), vec![]);
+/// }
+/// ```
+pub fn lint(open_api: &OpenApi) -> Vec<LintIssue> {
+  lint_with_metadata(open_api, &IndexMap::new())
+}
+
+/// Check the consistency of the openapi document along with the metadata gathered while documenting its paths.
+pub(crate) fn lint_with_metadata(
+  open_api: &OpenApi,
+  paths_metadata: &IndexMap<String, PathItemMetadata>,
+) -> Vec<LintIssue> {
+  let mut issues = dangling_references(open_api);
+  issues.extend(
+    duplicated_operation_ids(open_api)
+      .into_iter()
+      .map(|(operation_id, operations)| LintIssue::DuplicatedOperationId {
+        operation_id,
+        operations,
+      }),
+  );
+
+  let declared_tags = open_api
+    .tags
+    .iter()
+    .map(|tag| tag.name.as_str())
+    .collect::<BTreeSet<_>>();
+  let declared_schemes = open_api
+    .components
+    .as_ref()
+    .map(|components| {
+      components
+        .security_schemes
+        .keys()
+        .map(String::as_str)
+        .collect::<BTreeSet<_>>()
+    })
+    .unwrap_or_default();
+
+  issues.extend(
+    open_api
+      .security
+      .iter()
+      .flat_map(|requirement| requirement.requirements.keys())
+      .filter(|name| !declared_schemes.contains(name.as_str()))
+      .map(|name| LintIssue::UndeclaredSecurityScheme {
+        name: name.clone(),
+        operation: None,
+      }),
+  );
+
+  for (path, path_item) in &open_api.paths.paths {
+    let path_metadata = paths_metadata.get(path);
+    for operation_type in path_metadata
+      .map(|metadata| metadata.duplicated_operations.as_slice())
+      .unwrap_or_default()
+    {
+      issues.push(LintIssue::DuplicatedOperation {
+        operation: operation_name(*operation_type, path),
+      });
+    }
+
+    for (operation_type, operation) in &path_item.operations {
+      let name = operation_name(*operation_type, path);
+      let security_scope_names = path_metadata
+        .and_then(|metadata| metadata.operations.get(operation_type))
+        .map(|metadata| metadata.security_scope_names.as_slice())
+        .unwrap_or_default();
+      issues.extend(
+        operation
+          .tags
+          .iter()
+          .filter(|tag| !declared_tags.contains(tag.as_str()))
+          .map(|tag| LintIssue::UndeclaredTag {
+            tag: tag.clone(),
+            operation: name.clone(),
+          }),
+      );
+      issues.extend(
+        operation
+          .security
+          .iter()
+          .flatten()
+          .flat_map(|requirement| requirement.requirements.keys())
+          .chain(security_scope_names)
+          .collect::<BTreeSet<_>>()
+          .into_iter()
+          .filter(|scheme| !declared_schemes.contains(scheme.as_str()))
+          .map(|scheme| LintIssue::UndeclaredSecurityScheme {
+            name: scheme.clone(),
+            operation: Some(name.clone()),
+          }),
+      );
+      issues.extend(path_parameter_issues(open_api, path, path_item, operation, &name));
+    }
+  }
+  issues.extend(undocumented_response_targets(open_api, paths_metadata));
+  issues
+}
+
+/// Status codes targeted by `response_header`, `link` or `response_example` without matching response on their operation
+pub(crate) fn undocumented_response_targets(
+  open_api: &OpenApi,
+  paths_metadata: &IndexMap<String, PathItemMetadata>,
+) -> Vec<LintIssue> {
+  let mut issues = vec![];
+  for (path, path_item) in &open_api.paths.paths {
+    let Some(path_metadata) = paths_metadata.get(path) else {
+      continue;
+    };
+    for (operation_type, operation) in &path_item.operations {
+      let response_target_codes = path_metadata
+        .operations
+        .get(operation_type)
+        .map(|metadata| metadata.response_target_codes.as_slice())
+        .unwrap_or_default();
+      issues.extend(
+        response_target_codes
+          .iter()
+          .filter(|status| {
+            operation
+              .responses
+              .responses
+              .get(&status.to_string())
+              .and_then(ReferenceOr::get_object_ref)
+              .is_none()
+          })
+          .map(|status| LintIssue::UndocumentedResponseTarget {
+            status: *status,
+            operation: operation_name(*operation_type, path),
+          }),
+      );
+    }
+  }
+  issues
+}
+
+fn operation_name(operation_type: OperationType, path: &str) -> String {
+  format!("{} {path}", format!("{operation_type:?}").to_uppercase())
+}
+
+/// Compare the parameters of the path template to the `path` parameters documented on the operation or its path item
+fn path_parameter_issues(
+  open_api: &OpenApi,
+  path: &str,
+  path_item: &PathItem,
+  operation: &Operation,
+  name: &str,
+) -> Vec<LintIssue> {
+  let template_parameters = path
+    .split('/')
+    .filter_map(|segment| segment.strip_prefix('{').and_then(|segment| segment.strip_suffix('}')))
+    .collect::<BTreeSet<_>>();
+  let documented_parameters = path_item
+    .parameters
+    .iter()
+    .chain(&operation.parameters)
+    .filter_map(|parameter| resolve_parameter(open_api, parameter))
+    .filter(|parameter| parameter._in == ParameterIn::Path)
+    .map(|parameter| parameter.name.as_str())
+    .collect::<BTreeSet<_>>();
+
+  let missing =
+    template_parameters
+      .difference(&documented_parameters)
+      .map(|parameter| LintIssue::MissingPathParameter {
+        parameter: (*parameter).to_owned(),
+        operation: name.to_owned(),
+      });
+  let unknown =
+    documented_parameters
+      .difference(&template_parameters)
+      .map(|parameter| LintIssue::UnknownPathParameter {
+        parameter: (*parameter).to_owned(),
+        operation: name.to_owned(),
+      });
+  missing.chain(unknown).collect()
+}
+
+fn resolve_parameter<'a>(open_api: &'a OpenApi, parameter: &'a ReferenceOr<Parameter>) -> Option<&'a Parameter> {
+  match parameter {
+    ReferenceOr::Object(parameter) => Some(parameter),
+    ReferenceOr::Reference { _ref } => _ref
+      .strip_prefix("#/components/parameters/")
+      .and_then(|name| open_api.components.as_ref()?.parameters.get(name))
+      .and_then(ReferenceOr::get_object_ref),
+  }
+}
+
+/// Local `$ref`s which can't be resolved within the serialized document
+fn dangling_references(open_api: &OpenApi) -> Vec<LintIssue> {
+  let Ok(document) = serde_json::to_value(open_api) else {
+    return vec![];
+  };
+  let mut issues = vec![];
+  collect_dangling_references(&document, &document, "", &mut issues);
+  issues
+}
+
+fn collect_dangling_references(document: &Value, value: &Value, location: &str, issues: &mut Vec<LintIssue>) {
+  match value {
+    Value::Object(object) => {
+      for (key, value) in object {
+        let location = format!("{location}/{}", key.replace('~', "~0").replace('/', "~1"));
+        match value {
+          Value::String(reference) if key == "$ref" => {
+            let resolved = reference
+              .strip_prefix('#')
+              .is_none_or(|pointer| document.pointer(pointer).is_some());
+            if !resolved {
+              issues.push(LintIssue::DanglingReference {
+                location,
+                reference: reference.clone(),
+              });
+            }
+          }
+          _ => collect_dangling_references(document, value, &location, issues),
+        }
+      }
+    }
+    Value::Array(values) => {
+      for (index, value) in values.iter().enumerate() {
+        collect_dangling_references(document, value, &format!("{location}/{index}"), issues);
+      }
+    }
+    _ => {}
+  }
+}
@@ -1,14 +1,26 @@
 use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::Arc;
+use std::{fs, io};
 
 use apistos_models::security::SecurityScheme;
+use convert_case::{Case, Casing, Converter};
+use indexmap::IndexMap;
 use schemars::schema::Schema;
+use serde::Serialize;
+use serde_json::Value;
 
+use crate::app::build_operation_id;
+use crate::internal::definition_holder::OperationMetadata;
 use apistos_core::ApiComponent;
 use apistos_models::info::Info;
-use apistos_models::paths::{ExternalDocumentation, Parameter};
+use apistos_models::paths::{
+  Example, ExampleValue, ExternalDocumentation, Operation, OperationType, Parameter, PathItem,
+};
 use apistos_models::reference_or::ReferenceOr;
 use apistos_models::server::Server;
 use apistos_models::tag::Tag;
+use apistos_models::{OpenApi, OpenApiVersion};
 
 /// Defines an accessor for `DefaultParameters`
 pub trait DefaultParameterAccessor {
@@ -40,6 +52,10 @@ pub struct DefaultParameters {
 
 #[derive(Default, Clone)]
 pub struct Spec {
+  /// Version of the generated document. When set to [`OpenApiVersion::OAS3_1`], schemas are converted to JSON Schema 2020-12 when the app is built.
+  pub openapi_version: OpenApiVersion,
+  /// Default `$schema` dialect of the schemas. Only used with OAS 3.1.
+  pub json_schema_dialect: Option<String>,
   pub info: Info,
   pub default_tags: Vec<String>,
   /// See more details at <https://spec.openapis.org/oas/latest.html#tagObject>.
@@ -52,4 +68,225 @@ pub struct Spec {
   pub default_parameters: Vec<DefaultParameters>,
   /// Global security
   pub securities: Vec<BTreeMap<String, SecurityScheme>>,
+  /// Webhooks the API consumer MAY implement. Only used with OAS 3.1, see more details at <https://spec.openapis.org/oas/v3.1.0#fixed-fields>.
+  pub webhooks: BTreeMap<String, PathItem>,
+  /// [Specification extensions](https://spec.openapis.org/oas/latest.html#specification-extensions) of the document, e.g. `x-amazon-apigateway-cors`.
+  ///
+  /// Extensions whose name doesn't start with `x-` are ignored, see [`extension`].
+  pub extensions: IndexMap<String, Value>,
+  /// Groups of tags exposed as the `x-tagGroups` extension, rendered as nested navigation by Redoc and Scalar.
+  /// Groups with the same name as a group of an `x-tagGroups` entry of `extensions` are ignored, the extension taking precedence.
+  pub tag_groups: Vec<TagGroup>,
+  /// How `operationId` is generated for operations without explicit `operation_id`.
+  pub operation_id_strategy: OperationIdStrategy,
+}
+
+/// A named group of tags, see <https://redocly.com/docs-legacy/api-reference-docs/specification-extensions/x-tag-groups>.
+///
+/// Redoc hides tags which are not part of any group as soon as groups are declared.
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct TagGroup {
+  pub name: String,
+  /// Names of the tags of this group
+  pub tags: Vec<String>,
+}
+
+impl TagGroup {
+  pub fn new<N: Into<String>, T: Into<String>>(name: N, tags: Vec<T>) -> Self {
+    Self {
+      name: name.into(),
+      tags: tags.into_iter().map(Into::into).collect(),
+    }
+  }
+}
+
+/// Custom operation id generator, called with the path, the method and the operation.
+pub type OperationIdFn = Arc<dyn Fn(&str, OperationType, &Operation) -> String + Send + Sync>;
+
+/// Strategy generating the `operationId` of operations without explicit `operation_id`.
+///
+/// Strategies relying on a name fall back to [`OperationIdStrategy::Hash`] when the name is not available. Generated ids colliding with other operation ids are reported through a warning when the app is built.
+#[derive(Default, Clone)]
+pub enum OperationIdStrategy {
+  /// The method and the first path segment, followed by a md5 hash of the path, e.g. `get_api-v1-pets-89654e0732d51aafdc164076a57fd663`
+  #[default]
+  Hash,
+  /// The name of the handler function documented with `#[api_operation]`, e.g. `get_pet`
+  HandlerName,
+  /// The method followed by the name given through `Resource::name`, e.g. `get_pet`
+  ResourceName,
+  /// The method and the path in camel case, path parameters being prefixed by `by`, e.g. `getPetsById` for `GET /pets/{id}`. Acronyms are kept uppercase, e.g. `getPetsByPetID` for `GET /pets/{petID}`
+  CamelCase,
+  /// The method and the path in snake case, path parameters being prefixed by `by`, e.g. `get_pets_by_id` for `GET /pets/{id}`
+  SnakeCase,
+  /// A user provided function, see [`OperationIdFn`]
+  Custom(OperationIdFn),
+}
+
+impl OperationIdStrategy {
+  /// Generate the operation id of **`operation`** served with **`operation_type`** at **`path`**.
+  pub(crate) fn operation_id(
+    &self,
+    path: &str,
+    operation_type: OperationType,
+    operation: &Operation,
+    metadata: &OperationMetadata,
+  ) -> String {
+    let method = format!("{operation_type:?}").to_lowercase();
+    let id = match self {
+      Self::Hash => None,
+      Self::HandlerName => metadata.handler_name.clone(),
+      Self::ResourceName => metadata
+        .resource_name
+        .as_ref()
+        .map(|resource_name| format!("{method}_{resource_name}")),
+      Self::CamelCase => Some(
+        Converter::new()
+          .to_case(Case::Camel)
+          .set_pattern(camel_keeping_acronyms)
+          .convert(path_words(&method, path)),
+      ),
+      Self::SnakeCase => Some(path_words(&method, path).to_case(Case::Snake)),
+      Self::Custom(operation_id) => Some(operation_id(path, operation_type, operation)),
+    };
+    id.unwrap_or_else(|| build_operation_id(path, operation_type))
+  }
+}
+
+/// Words of the method and of the path separated by spaces, path parameters being prefixed by `by`
+fn path_words(method: &str, path: &str) -> String {
+  let segments = path
+    .split('/')
+    .filter(|segment| !segment.is_empty())
+    .flat_map(|segment| {
+      let (prefix, segment) = match segment.strip_prefix('{').and_then(|segment| segment.strip_suffix('}')) {
+        // patterned parameters such as `{id:\d+}` are named before the colon
+        Some(parameter) => (Some("by"), parameter.split(':').next().unwrap_or(parameter)),
+        None => (None, segment),
+      };
+      prefix.into_iter().chain(
+        segment
+          .split(|c: char| !c.is_ascii_alphanumeric())
+          .filter(|word| !word.is_empty()),
+      )
+    });
+  std::iter::once(method).chain(segments).collect::<Vec<_>>().join(" ")
+}
+
+/// Camel case pattern keeping uppercase words such as `ID` as is
+fn camel_keeping_acronyms(words: &[&str]) -> Vec<String> {
+  words
+    .iter()
+    .enumerate()
+    .map(|(index, word)| {
+      if index == 0 {
+        word.to_lowercase()
+      } else if word.chars().count() > 1 && !word.chars().any(char::is_lowercase) {
+        (*word).to_owned()
+      } else {
+        word.to_case(Case::Pascal)
+      }
+    })
+    .collect()
+}
+
+/// Serialization format of a generated openapi specification.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SpecFormat {
+  #[default]
+  Json,
+  /// Requires the `yaml` feature.
+  #[cfg(feature = "yaml")]
+  Yaml,
+}
+
+impl SpecFormat {
+  /// Deduce the format from a file extension, `.yaml` and `.yml` files being serialized as yaml and everything else as json.
+  ///
+  /// Yaml files are rejected with an [`io::ErrorKind::Unsupported`] error when the `yaml` feature is disabled.
+  pub fn from_path<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+    match path.as_ref().extension().and_then(|ext| ext.to_str()) {
+      #[cfg(feature = "yaml")]
+      Some("yaml" | "yml") => Ok(Self::Yaml),
+      #[cfg(not(feature = "yaml"))]
+      Some("yaml" | "yml") => Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "writing a yaml specification requires the `yaml` feature",
+      )),
+      _ => Ok(Self::Json),
+    }
+  }
+
+  /// Media type of the serialized specification.
+  pub fn content_type(&self) -> &'static str {
+    match self {
+      Self::Json => "application/json",
+      #[cfg(feature = "yaml")]
+      Self::Yaml => "application/yaml",
+    }
+  }
+
+  /// Serialize the specification in a human readable way.
+  pub fn to_vec(&self, open_api: &OpenApi) -> io::Result<Vec<u8>> {
+    match self {
+      Self::Json => serde_json::to_vec_pretty(open_api).map_err(io::Error::other),
+      #[cfg(feature = "yaml")]
+      Self::Yaml => serde_yaml_ng::to_string(open_api)
+        .map(String::into_bytes)
+        .map_err(io::Error::other),
+    }
+  }
+
+  /// Serialize the specification to **`path`**, creating or truncating the file.
+  pub fn write<P: AsRef<Path>>(&self, open_api: &OpenApi, path: P) -> io::Result<()> {
+    fs::write(path, self.to_vec(open_api)?)
+  }
+}
+
+/// Entry of the [specification extension](https://spec.openapis.org/oas/latest.html#specification-extensions) **`name`**, used for every extension declared through apistos.
+///
+/// Extensions whose name doesn't start with `x-` or whose value can't be serialized are ignored, a warning being logged.
+pub fn extension<N: Into<String>, V: Serialize>(name: N, value: V) -> Option<(String, Value)> {
+  let name = name.into();
+  if !name.starts_with("x-") {
+    log::warn!("Extension `{name}` is ignored as it doesn't start with `x-`");
+    return None;
+  }
+  match serde_json::to_value(value) {
+    Ok(value) => Some((name, value)),
+    Err(e) => {
+      log::warn!("Extension `{name}` is ignored as its value can't be serialized: {e}");
+      None
+    }
+  }
+}
+
+/// Entry of the [example](https://spec.openapis.org/oas/latest.html#example-object) **`name`** of value **`value`**, used for every example declared through apistos.
+///
+/// Examples whose value can't be serialized are ignored, a warning being logged.
+pub fn example<N: Into<String>, V: Serialize>(name: N, value: V) -> Option<(String, Example)> {
+  let name = name.into();
+  match serde_json::to_value(value) {
+    Ok(value) => Some((
+      name,
+      Example {
+        summary: None,
+        description: None,
+        value: ExampleValue::Value(value),
+        extensions: Default::default(),
+      },
+    )),
+    Err(e) => {
+      log::warn!("Example `{name}` is ignored as its value can't be serialized: {e}");
+      None
+    }
+  }
+}
+
+/// Write **`open_api`** to **`path`**, the format being deduced from the file extension, see [`SpecFormat::from_path`].
+///
+/// Useful to export a specification which was not generated from an [`App`](crate::app::App), see [`App::write_spec`](crate::app::App::write_spec) otherwise.
+pub fn write_spec<P: AsRef<Path>>(open_api: &OpenApi, path: P) -> io::Result<()> {
+  SpecFormat::from_path(&path)?.write(open_api, path)
 }
@@ -0,0 +1,456 @@
+use actix_web::http::{Method, StatusCode};
+use apistos_models::OpenApi;
+use apistos_models::paths::{
+  MediaType, Operation, OperationType, Parameter, ParameterDefinition, ParameterIn, ParameterStyle, Response,
+};
+use apistos_models::reference_or::ReferenceOr;
+use jsonschema::{Draft, Registry, Validator};
+use regex::Regex;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+
+/// Base uri the openapi document is registered under so that `#/components/...` references can be resolved from any schema.
+const OPENAPI_DOCUMENT_URI: &str = "urn:apistos:openapi";
+
+/// Operations of an openapi document with their schemas compiled to validators.
+pub(crate) struct CompiledSpec {
+  operations: Vec<CompiledOperation>,
+}
+
+pub(crate) struct CompiledOperation {
+  method: Method,
+  path_regex: Regex,
+  path_parameter_names: Vec<String>,
+  pub(crate) parameters: Vec<CompiledParameter>,
+  pub(crate) request_body: Option<CompiledRequestBody>,
+  pub(crate) responses: Vec<CompiledResponse>,
+}
+
+pub(crate) struct CompiledParameter {
+  pub(crate) name: String,
+  pub(crate) location: ParameterIn,
+  pub(crate) required: bool,
+  pub(crate) kind: ParameterKind,
+  pub(crate) validator: Option<Validator>,
+}
+
+/// How raw string values of a parameter should be converted to json before validation.
+pub(crate) enum ParameterKind {
+  Scalar(Vec<String>),
+  Array(Vec<String>),
+  /// Objects (e.g. deep object query parameters) are not validated
+  Unsupported,
+}
+
+pub(crate) struct CompiledRequestBody {
+  pub(crate) required: bool,
+  pub(crate) content: Vec<CompiledMediaType>,
+}
+
+pub(crate) struct CompiledResponse {
+  /// Status code, status code range (e.g. `4XX`) or `default`
+  status: String,
+  pub(crate) content: Vec<CompiledMediaType>,
+}
+
+pub(crate) struct CompiledMediaType {
+  pub(crate) media_type: String,
+  pub(crate) validator: Option<Validator>,
+}
+
+/// Builds validators for schemas contained in an openapi document.
+pub(crate) struct SchemaCompiler {
+  document: Value,
+  registry: Option<Registry>,
+}
+
+impl SchemaCompiler {
+  /// Schemas are compiled as JSON Schema 2020-12, the document is expected to be converted to OAS 3.1 beforehand.
+  pub(crate) fn new(open_api: &OpenApi) -> Self {
+    let document = serde_json::to_value(open_api).unwrap_or_default();
+    let registry = Registry::try_new(
+      OPENAPI_DOCUMENT_URI,
+      Draft::Draft202012.create_resource(document.clone()),
+    )
+    .map_err(|err| log::warn!("Unable to register openapi document for validation: {err}"))
+    .ok();
+    Self { document, registry }
+  }
+
+  /// Build a validator for the given schema, logging a warning if the schema can't be compiled.
+  pub(crate) fn compile<T: Serialize>(&self, schema: &T) -> Option<Validator> {
+    let registry = self.registry.clone()?;
+    let mut schema = serde_json::to_value(schema).ok()?;
+    absolutize_references(&mut schema);
+    jsonschema::options()
+      .with_draft(Draft::Draft202012)
+      .with_registry(registry)
+      .build(&schema)
+      .map_err(|err| log::warn!("Unable to compile schema for validation: {err}"))
+      .ok()
+  }
+
+  /// The openapi document serialized as json.
+  pub(crate) fn document(&self) -> &Value {
+    &self.document
+  }
+
+  /// Follow a local `$ref` in the converted document.
+  pub(crate) fn resolve<'a>(&'a self, value: &'a Value) -> &'a Value {
+    let mut value = value;
+    // bounded to avoid looping over self referencing schemas
+    for _ in 0..8 {
+      match value
+        .get("$ref")
+        .and_then(Value::as_str)
+        .and_then(|r| r.strip_prefix('#'))
+      {
+        Some(pointer) => match self.document.pointer(pointer) {
+          Some(resolved) => value = resolved,
+          None => break,
+        },
+        None => break,
+      }
+    }
+    value
+  }
+}
+
+impl CompiledSpec {
+  pub(crate) fn new(open_api: &OpenApi) -> Self {
+    let mut open_api = open_api.clone();
+    open_api.upgrade_to_oas3_1();
+    let open_api = &open_api;
+    let compiler = &SchemaCompiler::new(open_api);
+
+    let mut operations = vec![];
+    for (path, path_item) in &open_api.paths.paths {
+      let (path_regex, path_parameter_names) = match path_regex(path) {
+        Ok(path_regex) => path_regex,
+        Err(err) => {
+          log::warn!("Unable to build matcher for path {path}: {err}");
+          continue;
+        }
+      };
+      for (operation_type, operation) in &path_item.operations {
+        let parameters = path_item
+          .parameters
+          .iter()
+          .chain(&operation.parameters)
+          .filter_map(|parameter| resolve_parameter(open_api, parameter))
+          .map(|parameter| CompiledParameter::new(parameter, compiler))
+          .collect();
+        operations.push(CompiledOperation {
+          method: method(*operation_type),
+          path_regex: path_regex.clone(),
+          path_parameter_names: path_parameter_names.clone(),
+          parameters,
+          request_body: CompiledRequestBody::new(open_api, operation, compiler),
+          responses: CompiledResponse::from_operation(open_api, operation, compiler),
+        });
+      }
+    }
+    // literal paths take precedence over templated ones
+    operations.sort_by_key(|operation| operation.path_parameter_names.len());
+    Self { operations }
+  }
+
+  /// Find the operation matching the request method and path, with the raw values of its path parameters.
+  pub(crate) fn find(&self, method: &Method, path: &str) -> Option<(&CompiledOperation, HashMap<String, String>)> {
+    self
+      .operations
+      .iter()
+      .filter(|operation| operation.method == method)
+      .find_map(|operation| {
+        let captures = operation.path_regex.captures(path)?;
+        let path_parameters = operation
+          .path_parameter_names
+          .iter()
+          .zip(captures.iter().skip(1))
+          .filter_map(|(name, value)| value.map(|value| (name.clone(), value.as_str().to_owned())))
+          .collect();
+        Some((operation, path_parameters))
+      })
+  }
+}
+
+impl CompiledOperation {
+  /// Find the response documented for a status code, falling back to its status code range then to the default response.
+  pub(crate) fn response(&self, status: StatusCode) -> Option<&CompiledResponse> {
+    let status_code = status.as_str();
+    let status_range = format!("{}XX", status.as_u16() / 100);
+    [status_code, status_range.as_str(), "default"]
+      .iter()
+      .find_map(|candidate| {
+        self
+          .responses
+          .iter()
+          .find(|response| response.status.eq_ignore_ascii_case(candidate))
+      })
+  }
+
+  /// Status codes documented for this operation.
+  pub(crate) fn documented_statuses(&self) -> Vec<&str> {
+    self.responses.iter().map(|response| response.status.as_str()).collect()
+  }
+}
+
+impl CompiledParameter {
+  fn new(parameter: &Parameter, compiler: &SchemaCompiler) -> Self {
+    let schema = match &parameter.definition {
+      Some(ParameterDefinition::Schema(schema)) => serde_json::to_value(schema).ok(),
+      _ => None,
+    };
+    let kind = match (&parameter.style, schema.as_ref()) {
+      (Some(ParameterStyle::DeepObject), _) | (_, None) => ParameterKind::Unsupported,
+      (_, Some(schema)) => {
+        let schema = compiler.resolve(schema);
+        let types = schema_types(compiler, schema);
+        if types.iter().any(|t| t == "array") {
+          let item_types = schema
+            .get("items")
+            .map(|items| schema_types(compiler, compiler.resolve(items)))
+            .unwrap_or_default();
+          ParameterKind::Array(item_types)
+        } else if types.iter().any(|t| t == "object") {
+          ParameterKind::Unsupported
+        } else {
+          ParameterKind::Scalar(types)
+        }
+      }
+    };
+    let validator = match kind {
+      ParameterKind::Unsupported => None,
+      _ => schema.as_ref().and_then(|schema| compiler.compile(schema)),
+    };
+
+    Self {
+      name: parameter.name.clone(),
+      location: parameter._in.clone(),
+      required: parameter.required.unwrap_or(parameter._in == ParameterIn::Path),
+      kind,
+      validator,
+    }
+  }
+
+  /// Convert raw values of the parameter to json according to its schema.
+  pub(crate) fn to_json(&self, values: &[&str]) -> Option<Value> {
+    match &self.kind {
+      ParameterKind::Scalar(types) => values.first().map(|value| coerce(value, types)),
+      ParameterKind::Array(types) => {
+        let values: Vec<&str> = match values {
+          [single] => single.split(',').collect(),
+          values => values.to_vec(),
+        };
+        Some(Value::Array(values.iter().map(|value| coerce(value, types)).collect()))
+      }
+      ParameterKind::Unsupported => None,
+    }
+  }
+}
+
+impl CompiledRequestBody {
+  fn new(open_api: &OpenApi, operation: &Operation, compiler: &SchemaCompiler) -> Option<Self> {
+    let request_body = match operation.request_body.as_ref()? {
+      ReferenceOr::Object(request_body) => request_body,
+      ReferenceOr::Reference { _ref } => {
+        let name = _ref.strip_prefix("#/components/requestBodies/")?;
+        open_api
+          .components
+          .as_ref()?
+          .request_bodies
+          .get(name)?
+          .get_object_ref()?
+      }
+    };
+    Some(Self {
+      required: request_body.required.unwrap_or_default(),
+      content: CompiledMediaType::from_content(&request_body.content, compiler),
+    })
+  }
+
+  /// Find the documented media type matching a content type, handling `type/*` and `*/*` ranges.
+  pub(crate) fn media_type(&self, content_type: &str) -> Option<&CompiledMediaType> {
+    media_type_matching(&self.content, content_type, |media_type| &media_type.media_type)
+  }
+}
+
+impl CompiledResponse {
+  fn from_operation(open_api: &OpenApi, operation: &Operation, compiler: &SchemaCompiler) -> Vec<Self> {
+    operation
+      .responses
+      .responses
+      .iter()
+      .map(|(status, response)| (status.as_str(), response))
+      .chain(operation.responses.default.iter().map(|response| ("default", response)))
+      .filter_map(|(status, response)| {
+        let response = match response {
+          ReferenceOr::Object(response) => response,
+          ReferenceOr::Reference { _ref } => resolve_response(open_api, _ref)?,
+        };
+        Some(Self {
+          status: status.to_owned(),
+          content: CompiledMediaType::from_content(&response.content, compiler),
+        })
+      })
+      .collect()
+  }
+
+  /// Find the documented media type matching a content type, handling `type/*` and `*/*` ranges.
+  pub(crate) fn media_type(&self, content_type: &str) -> Option<&CompiledMediaType> {
+    media_type_matching(&self.content, content_type, |media_type| &media_type.media_type)
+  }
+}
+
+impl CompiledMediaType {
+  /// Validators are only built for json media types.
+  fn from_content(content: &BTreeMap<String, MediaType>, compiler: &SchemaCompiler) -> Vec<Self> {
+    content
+      .iter()
+      .map(|(media_type, content)| Self {
+        media_type: media_type.clone(),
+        validator: content
+          .schema
+          .as_ref()
+          .filter(|_| is_json(media_type))
+          .and_then(|schema| compiler.compile(schema)),
+      })
+      .collect()
+  }
+}
+
+/// Find the entry whose media type (possibly a range) matches **`content_type`**, exact matches first.
+pub(crate) fn media_type_matching<'a, T, F>(entries: &'a [T], content_type: &str, media_type: F) -> Option<&'a T>
+where
+  F: Fn(&T) -> &str,
+{
+  let essence = |media_type: &str| {
+    media_type
+      .split(';')
+      .next()
+      .unwrap_or_default()
+      .trim()
+      .to_ascii_lowercase()
+  };
+  let content_type = essence(content_type);
+  let main_type = content_type.split('/').next().unwrap_or_default();
+  entries
+    .iter()
+    .find(|entry| essence(media_type(entry)) == content_type)
+    .or_else(|| {
+      entries
+        .iter()
+        .find(|entry| essence(media_type(entry)) == format!("{main_type}/*"))
+    })
+    .or_else(|| entries.iter().find(|entry| essence(media_type(entry)) == "*/*"))
+}
+
+pub(crate) fn is_json(media_type: &str) -> bool {
+  let essence = media_type.split(';').next().unwrap_or_default().trim();
+  essence.eq_ignore_ascii_case("application/json") || essence.to_ascii_lowercase().ends_with("+json")
+}
+
+fn resolve_parameter<'a>(open_api: &'a OpenApi, parameter: &'a ReferenceOr<Parameter>) -> Option<&'a Parameter> {
+  match parameter {
+    ReferenceOr::Object(parameter) => Some(parameter),
+    ReferenceOr::Reference { _ref } => {
+      let name = _ref.strip_prefix("#/components/parameters/")?;
+      open_api.components.as_ref()?.parameters.get(name)?.get_object_ref()
+    }
+  }
+}
+
+fn resolve_response<'a>(open_api: &'a OpenApi, reference: &str) -> Option<&'a Response> {
+  let name = reference.strip_prefix("#/components/responses/")?;
+  open_api.components.as_ref()?.responses.get(name)?.get_object_ref()
+}
+
+fn schema_types(compiler: &SchemaCompiler, schema: &Value) -> Vec<String> {
+  match schema.get("type") {
+    Some(Value::String(t)) => vec![t.clone()],
+    Some(Value::Array(types)) => types.iter().filter_map(Value::as_str).map(str::to_owned).collect(),
+    _ => ["anyOf", "oneOf", "allOf"]
+      .iter()
+      .filter_map(|keyword| schema.get(keyword).and_then(Value::as_array))
+      .flatten()
+      .flat_map(|subschema| schema_types(compiler, compiler.resolve(subschema)))
+      .collect(),
+  }
+}
+
+fn coerce(value: &str, types: &[String]) -> Value {
+  let accepts = |t: &str| types.iter().any(|candidate| candidate == t);
+  if accepts("integer") {
+    if let Ok(value) = value.parse::<i64>() {
+      return Value::from(value);
+    }
+    if let Ok(value) = value.parse::<u64>() {
+      return Value::from(value);
+    }
+  }
+  if accepts("number") {
+    if let Some(value) = value.parse::<f64>().ok().and_then(serde_json::Number::from_f64) {
+      return Value::Number(value);
+    }
+  }
+  if accepts("boolean") {
+    if let Ok(value) = value.parse::<bool>() {
+      return Value::Bool(value);
+    }
+  }
+  if accepts("null") && value.is_empty() {
+    return Value::Null;
+  }
+  Value::String(value.to_owned())
+}
+
+/// Make local references point to the registered openapi document.
+fn absolutize_references(schema: &mut Value) {
+  match schema {
+    Value::Object(object) => {
+      for (key, value) in object.iter_mut() {
+        match value {
+          Value::String(reference) if key == "$ref" && reference.starts_with('#') => {
+            *reference = format!("{OPENAPI_DOCUMENT_URI}{reference}");
+          }
+          value => absolutize_references(value),
+        }
+      }
+    }
+    Value::Array(values) => values.iter_mut().for_each(absolutize_references),
+    _ => {}
+  }
+}
+
+/// Build a regex matching concrete paths of a path template, path parameters being captured in order.
+fn path_regex(path: &str) -> Result<(Regex, Vec<String>), regex::Error> {
+  let mut pattern = String::from("^");
+  let mut names = vec![];
+  let mut rest = path;
+  while let Some((literal, remaining)) = rest.split_once('{') {
+    let Some((name, remaining)) = remaining.split_once('}') else {
+      break;
+    };
+    pattern.push_str(&regex::escape(literal));
+    pattern.push_str("([^/]+)");
+    names.push(name.to_owned());
+    rest = remaining;
+  }
+  pattern.push_str(&regex::escape(rest));
+  pattern.push('$');
+  Regex::new(&pattern).map(|regex| (regex, names))
+}
+
+fn method(operation_type: OperationType) -> Method {
+  match operation_type {
+    OperationType::Get => Method::GET,
+    OperationType::Put => Method::PUT,
+    OperationType::Post => Method::POST,
+    OperationType::Delete => Method::DELETE,
+    OperationType::Options => Method::OPTIONS,
+    OperationType::Head => Method::HEAD,
+    OperationType::Patch => Method::PATCH,
+    OperationType::Trace => Method::TRACE,
+  }
+}
@@ -0,0 +1,183 @@
+use crate::validation::compiled::SchemaCompiler;
+use apistos_models::OpenApi;
+use serde_json::Value;
+use std::fmt::{Display, Formatter};
+
+/// Keywords holding subschemas, either directly, as arrays or as maps of subschemas.
+const SUBSCHEMA_KEYWORDS: &[&str] = &[
+  "items",
+  "additionalProperties",
+  "not",
+  "if",
+  "then",
+  "else",
+  "contains",
+  "propertyNames",
+  "unevaluatedItems",
+  "unevaluatedProperties",
+];
+const SUBSCHEMA_ARRAY_KEYWORDS: &[&str] = &["allOf", "anyOf", "oneOf", "prefixItems"];
+const SUBSCHEMA_MAP_KEYWORDS: &[&str] = &["properties", "patternProperties", "$defs", "definitions"];
+
+/// An example not complying with the schema it illustrates.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExampleViolation {
+  /// [JSON pointer](https://datatracker.ietf.org/doc/html/rfc6901) to the example in the openapi document
+  pub location: String,
+  pub reason: String,
+}
+
+impl Display for ExampleViolation {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}: {}", self.location, self.reason)
+  }
+}
+
+/// Validate every example of the openapi document against the schema it illustrates, returning the violations found.
+///
+/// Examples of schemas (e.g. from `#[schemars(example = "...")]`), of parameters, headers and media types are checked,
+/// `#/components/...` references being resolved. Examples defined through an external value are ignored.
+///
+/// ```rust,ignore
+/// use apistos::validation::validate_examples;
+///
+/// #[test]
+/// fn examples_are_valid() {
+///   let spec = App::new()
+///     .document(spec())
+///     .service(scope("/test").service(todo!()))
+///     .spec();
+///   assert_eq!(validate_examples(&spec), vec![]);
+/// }
+/// ```
+pub fn validate_examples(open_api: &OpenApi) -> Vec<ExampleViolation> {
+  let mut open_api = open_api.clone();
+  open_api.upgrade_to_oas3_1();
+  let compiler = SchemaCompiler::new(&open_api);
+  let document = compiler.document();
+
+  let mut violations = vec![];
+  if let Some(schemas) = document.pointer("/components/schemas").and_then(Value::as_object) {
+    for (name, schema) in schemas {
+      let location = format!("/components/schemas/{}", escape(name));
+      validate_schema_examples(&compiler, schema, &location, &mut violations);
+    }
+  }
+  validate_holders(&compiler, document, "", &mut violations);
+  violations
+}
+
+/// Walk the document looking for parameters, headers and media types, i.e. objects with both a schema and examples.
+fn validate_holders(compiler: &SchemaCompiler, value: &Value, location: &str, violations: &mut Vec<ExampleViolation>) {
+  match value {
+    Value::Object(object) => {
+      if let Some(schema) = object.get("schema").filter(|schema| schema.is_object()) {
+        let schema_location = format!("{location}/schema");
+        validate_schema_examples(compiler, schema, &schema_location, violations);
+        if let Some(example) = object.get("example") {
+          validate_example(compiler, schema, example, &format!("{location}/example"), violations);
+        }
+        if let Some(examples) = object.get("examples").and_then(Value::as_object) {
+          for (name, example) in examples {
+            let example = compiler.resolve(example);
+            if let Some(value) = example.get("value") {
+              let example_location = format!("{location}/examples/{}", escape(name));
+              validate_example(compiler, schema, value, &example_location, violations);
+            }
+          }
+        }
+      }
+      for (key, value) in object {
+        // schemas are walked on their own
+        if key == "schema" || (location == "/components" && key == "schemas") {
+          continue;
+        }
+        validate_holders(compiler, value, &format!("{location}/{}", escape(key)), violations);
+      }
+    }
+    Value::Array(values) => {
+      for (index, value) in values.iter().enumerate() {
+        validate_holders(compiler, value, &format!("{location}/{index}"), violations);
+      }
+    }
+    _ => {}
+  }
+}
+
+/// Validate `examples` of a schema and of its subschemas.
+fn validate_schema_examples(
+  compiler: &SchemaCompiler,
+  schema: &Value,
+  location: &str,
+  violations: &mut Vec<ExampleViolation>,
+) {
+  let Some(object) = schema.as_object() else {
+    return;
+  };
+  if let Some(examples) = object.get("examples").and_then(Value::as_array) {
+    for (index, example) in examples.iter().enumerate() {
+      validate_example(
+        compiler,
+        schema,
+        example,
+        &format!("{location}/examples/{index}"),
+        violations,
+      );
+    }
+  }
+  for keyword in SUBSCHEMA_KEYWORDS {
+    if let Some(subschema) = object.get(*keyword) {
+      validate_schema_examples(compiler, subschema, &format!("{location}/{keyword}"), violations);
+    }
+  }
+  for keyword in SUBSCHEMA_ARRAY_KEYWORDS {
+    for (index, subschema) in object
+      .get(*keyword)
+      .and_then(Value::as_array)
+      .into_iter()
+      .flatten()
+      .enumerate()
+    {
+      validate_schema_examples(
+        compiler,
+        subschema,
+        &format!("{location}/{keyword}/{index}"),
+        violations,
+      );
+    }
+  }
+  for keyword in SUBSCHEMA_MAP_KEYWORDS {
+    for (name, subschema) in object.get(*keyword).and_then(Value::as_object).into_iter().flatten() {
+      let subschema_location = format!("{location}/{keyword}/{}", escape(name));
+      validate_schema_examples(compiler, subschema, &subschema_location, violations);
+    }
+  }
+}
+
+fn validate_example(
+  compiler: &SchemaCompiler,
+  schema: &Value,
+  example: &Value,
+  location: &str,
+  violations: &mut Vec<ExampleViolation>,
+) {
+  let Some(validator) = compiler.compile(schema) else {
+    return;
+  };
+  violations.extend(validator.iter_errors(example).map(|error| {
+    let instance_path = error.instance_path().as_str();
+    ExampleViolation {
+      location: location.to_owned(),
+      reason: if instance_path.is_empty() {
+        error.to_string()
+      } else {
+        format!("{instance_path}: {error}")
+      },
+    }
+  }));
+}
+
+/// Escape a JSON pointer reference token.
+fn escape(token: &str) -> String {
+  token.replace('~', "~0").replace('/', "~1")
+}
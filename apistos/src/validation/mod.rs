@@ -0,0 +1,14 @@
+//! Runtime validation against the generated openapi specification.
+//!
+//! Schemas are validated as [JSON Schema 2020-12](https://json-schema.org/draft/2020-12/json-schema-core) using [jsonschema](https://crates.io/crates/jsonschema).
+
+mod compiled;
+mod examples;
+mod problem;
+mod request;
+mod response;
+
+pub use examples::{ExampleViolation, validate_examples};
+pub use problem::{InvalidParam, ProblemDetails};
+pub use request::{RequestValidation, RequestValidationMiddleware};
+pub use response::{OnViolation, ResponseValidation, ResponseValidationMiddleware};
@@ -0,0 +1,86 @@
+use actix_web::http::StatusCode;
+use actix_web::{HttpResponse, ResponseError};
+use serde::Serialize;
+use std::fmt::{Display, Formatter};
+
+/// [RFC 7807](https://datatracker.ietf.org/doc/html/rfc7807) problem details returned when a request or response doesn't comply with the openapi specification.
+#[derive(Serialize, Clone, Debug)]
+pub struct ProblemDetails {
+  /// A URI reference identifying the problem type, `about:blank` when the problem has no additional semantics beyond the status code.
+  #[serde(rename = "type")]
+  pub problem_type: String,
+  /// A short, human-readable summary of the problem type.
+  pub title: String,
+  /// The HTTP status code.
+  pub status: u16,
+  /// A human-readable explanation specific to this occurrence of the problem.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub detail: Option<String>,
+  /// A URI reference that identifies the specific occurrence of the problem.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub instance: Option<String>,
+  /// Details about each invalid part of the request.
+  #[serde(rename = "invalid-params", skip_serializing_if = "Vec::is_empty")]
+  pub invalid_params: Vec<InvalidParam>,
+}
+
+/// An invalid part of a request or response.
+#[derive(Serialize, Clone, Debug)]
+pub struct InvalidParam {
+  /// Name of the parameter or header, json pointer to the invalid value for bodies, or the status code.
+  pub name: String,
+  /// Location of the invalid value, either `path`, `query`, `header`, `cookie`, `body` or `status`.
+  #[serde(rename = "in")]
+  pub location: String,
+  /// Why the value is invalid.
+  pub reason: String,
+}
+
+impl ProblemDetails {
+  pub fn new(status: StatusCode) -> Self {
+    Self {
+      problem_type: "about:blank".to_owned(),
+      title: status.canonical_reason().unwrap_or_default().to_owned(),
+      status: status.as_u16(),
+      detail: None,
+      instance: None,
+      invalid_params: vec![],
+    }
+  }
+
+  pub fn with_detail<T: Into<String>>(mut self, detail: T) -> Self {
+    self.detail = Some(detail.into());
+    self
+  }
+
+  pub fn with_instance<T: Into<String>>(mut self, instance: T) -> Self {
+    self.instance = Some(instance.into());
+    self
+  }
+
+  pub fn with_invalid_params(mut self, invalid_params: Vec<InvalidParam>) -> Self {
+    self.invalid_params = invalid_params;
+    self
+  }
+}
+
+impl Display for ProblemDetails {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match &self.detail {
+      Some(detail) => write!(f, "{}: {detail}", self.title),
+      None => write!(f, "{}", self.title),
+    }
+  }
+}
+
+impl ResponseError for ProblemDetails {
+  fn status_code(&self) -> StatusCode {
+    StatusCode::from_u16(self.status).unwrap_or(StatusCode::BAD_REQUEST)
+  }
+
+  fn error_response(&self) -> HttpResponse {
+    HttpResponse::build(self.status_code())
+      .content_type("application/problem+json")
+      .json(self)
+  }
+}
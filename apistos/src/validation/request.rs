@@ -0,0 +1,248 @@
+use crate::validation::compiled::{
+  CompiledOperation, CompiledParameter, CompiledRequestBody, CompiledSpec, ParameterKind,
+};
+use crate::validation::problem::{InvalidParam, ProblemDetails};
+use actix_service::{Service, Transform};
+use actix_web::Error;
+use actix_web::body::EitherBody;
+use actix_web::dev::{Payload, ServiceRequest, ServiceResponse, forward_ready};
+use actix_web::http::StatusCode;
+use actix_web::http::header::CONTENT_TYPE;
+use actix_web::web::{Bytes, Query};
+use apistos_models::OpenApi;
+use apistos_models::paths::ParameterIn;
+use futures_util::FutureExt;
+use futures_util::future::LocalBoxFuture;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::future::{Ready, ready};
+use std::rc::Rc;
+use std::sync::{Arc, RwLock};
+
+/// Middleware validating incoming requests against the operation documented for their method and path, see [`App::validate_requests`](crate::app::App::validate_requests).
+///
+/// Parameters (presence and schema) and json request bodies (content type and schema) are validated, invalid requests being rejected with a [`ProblemDetails`] response.
+/// Requests not matching any documented operation are forwarded untouched.
+#[derive(Clone)]
+pub struct RequestValidation {
+  open_api: Arc<RwLock<OpenApi>>,
+}
+
+impl RequestValidation {
+  /// The specification is read when the app is started, once every operation has been documented.
+  pub(crate) fn new(open_api: Arc<RwLock<OpenApi>>) -> Self {
+    Self { open_api }
+  }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for RequestValidation
+where
+  S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+  B: 'static,
+{
+  type Response = ServiceResponse<EitherBody<B>>;
+  type Error = Error;
+  type Transform = RequestValidationMiddleware<S>;
+  type InitError = ();
+  type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+  #[allow(clippy::unwrap_used)]
+  fn new_transform(&self, service: S) -> Self::Future {
+    let spec = CompiledSpec::new(&self.open_api.read().unwrap());
+    ready(Ok(RequestValidationMiddleware {
+      service: Rc::new(service),
+      spec: Rc::new(spec),
+    }))
+  }
+}
+
+pub struct RequestValidationMiddleware<S> {
+  service: Rc<S>,
+  spec: Rc<CompiledSpec>,
+}
+
+impl<S, B> Service<ServiceRequest> for RequestValidationMiddleware<S>
+where
+  S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+  B: 'static,
+{
+  type Response = ServiceResponse<EitherBody<B>>;
+  type Error = Error;
+  type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+  forward_ready!(service);
+
+  fn call(&self, mut req: ServiceRequest) -> Self::Future {
+    let service = Rc::clone(&self.service);
+    let spec = Rc::clone(&self.spec);
+
+    async move {
+      if let Err(problem) = validate_request(&spec, &mut req).await {
+        return Ok(req.error_response(problem).map_into_right_body());
+      }
+      service.call(req).await.map(ServiceResponse::map_into_left_body)
+    }
+    .boxed_local()
+  }
+}
+
+async fn validate_request(spec: &CompiledSpec, req: &mut ServiceRequest) -> Result<(), ProblemDetails> {
+  let Some((operation, path_parameters)) = spec.find(req.method(), req.path()) else {
+    return Ok(());
+  };
+
+  let mut invalid_params = validate_parameters(operation, req, &path_parameters);
+  if let Some(request_body) = &operation.request_body {
+    invalid_params.append(&mut validate_body(request_body, req).await?);
+  }
+
+  if invalid_params.is_empty() {
+    Ok(())
+  } else {
+    Err(
+      ProblemDetails::new(StatusCode::BAD_REQUEST)
+        .with_detail("The request doesn't comply with the API specification")
+        .with_instance(req.path())
+        .with_invalid_params(invalid_params),
+    )
+  }
+}
+
+fn validate_parameters(
+  operation: &CompiledOperation,
+  req: &ServiceRequest,
+  path_parameters: &HashMap<String, String>,
+) -> Vec<InvalidParam> {
+  let query = Query::<Vec<(String, String)>>::from_query(req.query_string())
+    .map(Query::into_inner)
+    .unwrap_or_default();
+
+  operation
+    .parameters
+    .iter()
+    .filter(|parameter| !matches!(parameter.kind, ParameterKind::Unsupported))
+    .filter_map(|parameter| {
+      let name = parameter.name.as_str();
+      let values: Vec<String> = match parameter.location {
+        ParameterIn::Path => path_parameters.get(name).cloned().into_iter().collect(),
+        ParameterIn::Query => query
+          .iter()
+          .filter(|(key, _)| key == name || key.strip_suffix("[]") == Some(name))
+          .map(|(_, value)| value.clone())
+          .collect(),
+        ParameterIn::Header => req
+          .headers()
+          .get_all(name)
+          .filter_map(|value| value.to_str().ok())
+          .map(str::to_owned)
+          .collect(),
+        ParameterIn::Cookie => req
+          .cookie(name)
+          .map(|cookie| cookie.value().to_owned())
+          .into_iter()
+          .collect(),
+      };
+
+      if values.is_empty() {
+        return parameter
+          .required
+          .then(|| invalid_parameter(parameter, "Missing required parameter".to_owned()));
+      }
+
+      let value = parameter.to_json(&values.iter().map(String::as_str).collect::<Vec<_>>())?;
+      let errors = parameter
+        .validator
+        .as_ref()?
+        .iter_errors(&value)
+        .map(|error| error.to_string())
+        .collect::<Vec<_>>();
+      (!errors.is_empty()).then(|| invalid_parameter(parameter, errors.join(", ")))
+    })
+    .collect()
+}
+
+async fn validate_body(
+  request_body: &CompiledRequestBody,
+  req: &mut ServiceRequest,
+) -> Result<Vec<InvalidParam>, ProblemDetails> {
+  let Some(content_type) = req
+    .headers()
+    .get(CONTENT_TYPE)
+    .and_then(|content_type| content_type.to_str().ok())
+    .map(str::to_owned)
+  else {
+    return Ok(if request_body.required {
+      vec![invalid_body("", "Missing required request body".to_owned())]
+    } else {
+      vec![]
+    });
+  };
+
+  let Some(media_type) = request_body.media_type(&content_type) else {
+    let supported = request_body
+      .content
+      .iter()
+      .map(|media_type| media_type.media_type.as_str())
+      .collect::<Vec<_>>()
+      .join(", ");
+    return Err(
+      ProblemDetails::new(StatusCode::UNSUPPORTED_MEDIA_TYPE)
+        .with_detail(format!(
+          "Unsupported content type {content_type}, expected one of: {supported}"
+        ))
+        .with_instance(req.path()),
+    );
+  };
+  let Some(validator) = &media_type.validator else {
+    return Ok(vec![]);
+  };
+
+  let body = req.extract::<Bytes>().await.map_err(|err| {
+    ProblemDetails::new(StatusCode::BAD_REQUEST)
+      .with_detail(err.to_string())
+      .with_instance(req.path())
+  })?;
+  // the payload has been consumed, hand it back to the handler
+  req.set_payload(Payload::from(body.clone()));
+
+  if body.is_empty() {
+    return Ok(if request_body.required {
+      vec![invalid_body("", "Missing required request body".to_owned())]
+    } else {
+      vec![]
+    });
+  }
+
+  let body = match serde_json::from_slice::<Value>(&body) {
+    Ok(body) => body,
+    Err(err) => return Ok(vec![invalid_body("", err.to_string())]),
+  };
+  Ok(
+    validator
+      .iter_errors(&body)
+      .map(|error| invalid_body(error.instance_path().as_str(), error.to_string()))
+      .collect(),
+  )
+}
+
+fn invalid_parameter(parameter: &CompiledParameter, reason: String) -> InvalidParam {
+  let location = match parameter.location {
+    ParameterIn::Query => "query",
+    ParameterIn::Header => "header",
+    ParameterIn::Path => "path",
+    ParameterIn::Cookie => "cookie",
+  };
+  InvalidParam {
+    name: parameter.name.clone(),
+    location: location.to_owned(),
+    reason,
+  }
+}
+
+fn invalid_body(pointer: &str, reason: String) -> InvalidParam {
+  InvalidParam {
+    name: if pointer.is_empty() { "/" } else { pointer }.to_owned(),
+    location: "body".to_owned(),
+    reason,
+  }
+}
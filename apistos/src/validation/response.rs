@@ -0,0 +1,208 @@
+use crate::validation::compiled::{CompiledOperation, CompiledSpec};
+use crate::validation::problem::{InvalidParam, ProblemDetails};
+use actix_service::{Service, Transform};
+use actix_web::body::{self, BodySize, EitherBody, MessageBody};
+use actix_web::dev::{ServiceRequest, ServiceResponse, forward_ready};
+use actix_web::error::ErrorInternalServerError;
+use actix_web::http::StatusCode;
+use actix_web::http::header::CONTENT_TYPE;
+use actix_web::{Error, ResponseError};
+use apistos_models::OpenApi;
+use futures_util::FutureExt;
+use futures_util::future::LocalBoxFuture;
+use serde_json::Value;
+use std::future::{Ready, ready};
+use std::rc::Rc;
+use std::sync::{Arc, RwLock};
+
+/// What to do with responses which don't comply with the openapi specification.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OnViolation {
+  /// Log a warning and forward the response untouched
+  #[default]
+  Log,
+  /// Log a warning and replace the response by an internal server error describing the violations
+  Fail,
+}
+
+/// Middleware checking responses against the operation documented for their request method and path, see [`App::validate_responses`](crate::app::App::validate_responses).
+///
+/// Status codes are checked to be documented, content types to match the documented ones and json bodies to comply with their documented schema.
+/// Meant to be used in tests or debug builds, as json bodies are buffered to be validated.
+#[derive(Clone)]
+pub struct ResponseValidation {
+  open_api: Arc<RwLock<OpenApi>>,
+  on_violation: OnViolation,
+}
+
+impl ResponseValidation {
+  /// The specification is read when the app is started, once every operation has been documented.
+  pub(crate) fn new(open_api: Arc<RwLock<OpenApi>>, on_violation: OnViolation) -> Self {
+    Self { open_api, on_violation }
+  }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for ResponseValidation
+where
+  S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+  B: MessageBody + 'static,
+{
+  type Response = ServiceResponse<EitherBody<B>>;
+  type Error = Error;
+  type Transform = ResponseValidationMiddleware<S>;
+  type InitError = ();
+  type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+  #[allow(clippy::unwrap_used)]
+  fn new_transform(&self, service: S) -> Self::Future {
+    let spec = CompiledSpec::new(&self.open_api.read().unwrap());
+    ready(Ok(ResponseValidationMiddleware {
+      service: Rc::new(service),
+      spec: Rc::new(spec),
+      on_violation: self.on_violation,
+    }))
+  }
+}
+
+pub struct ResponseValidationMiddleware<S> {
+  service: Rc<S>,
+  spec: Rc<CompiledSpec>,
+  on_violation: OnViolation,
+}
+
+impl<S, B> Service<ServiceRequest> for ResponseValidationMiddleware<S>
+where
+  S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+  B: MessageBody + 'static,
+{
+  type Response = ServiceResponse<EitherBody<B>>;
+  type Error = Error;
+  type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+  forward_ready!(service);
+
+  fn call(&self, req: ServiceRequest) -> Self::Future {
+    let service = Rc::clone(&self.service);
+    let spec = Rc::clone(&self.spec);
+    let on_violation = self.on_violation;
+
+    async move {
+      let res = service.call(req).await?;
+      let Some((operation, _)) = spec.find(res.request().method(), res.request().path()) else {
+        return Ok(res.map_into_left_body());
+      };
+
+      let (res, violations) = validate_response(operation, res).await?;
+      if violations.is_empty() {
+        return Ok(res);
+      }
+
+      let reasons = violations
+        .iter()
+        .map(|violation| format!("{} ({}): {}", violation.name, violation.location, violation.reason))
+        .collect::<Vec<_>>()
+        .join(", ");
+      log::warn!(
+        "Response {} to {} {} doesn't comply with the API specification: {reasons}",
+        res.status(),
+        res.request().method(),
+        res.request().path()
+      );
+
+      match on_violation {
+        OnViolation::Log => Ok(res),
+        OnViolation::Fail => {
+          let problem = ProblemDetails::new(StatusCode::INTERNAL_SERVER_ERROR)
+            .with_detail("The response doesn't comply with the API specification")
+            .with_instance(res.request().path())
+            .with_invalid_params(violations);
+          let (req, _) = res.into_parts();
+          Ok(ServiceResponse::new(req, problem.error_response()).map_into_right_body())
+        }
+      }
+    }
+    .boxed_local()
+  }
+}
+
+async fn validate_response<B>(
+  operation: &CompiledOperation,
+  res: ServiceResponse<B>,
+) -> Result<(ServiceResponse<EitherBody<B>>, Vec<InvalidParam>), Error>
+where
+  B: MessageBody + 'static,
+{
+  let Some(response) = operation.response(res.status()) else {
+    let reason = format!(
+      "Undocumented status code, expected one of: {}",
+      operation.documented_statuses().join(", ")
+    );
+    let violations = vec![violation("status", res.status().as_str(), reason)];
+    return Ok((res.map_into_left_body(), violations));
+  };
+
+  let is_empty = matches!(res.response().body().size(), BodySize::None | BodySize::Sized(0));
+  if response.content.is_empty() || is_empty {
+    return Ok((res.map_into_left_body(), vec![]));
+  }
+
+  let expected = || {
+    response
+      .content
+      .iter()
+      .map(|media_type| media_type.media_type.as_str())
+      .collect::<Vec<_>>()
+      .join(", ")
+  };
+  let Some(content_type) = res
+    .headers()
+    .get(CONTENT_TYPE)
+    .and_then(|content_type| content_type.to_str().ok())
+    .map(str::to_owned)
+  else {
+    let reason = format!("Missing content type, expected one of: {}", expected());
+    return Ok((
+      res.map_into_left_body(),
+      vec![violation("header", "content-type", reason)],
+    ));
+  };
+  let Some(media_type) = response.media_type(&content_type) else {
+    let reason = format!(
+      "Undocumented content type {content_type}, expected one of: {}",
+      expected()
+    );
+    return Ok((
+      res.map_into_left_body(),
+      vec![violation("header", "content-type", reason)],
+    ));
+  };
+  let Some(validator) = &media_type.validator else {
+    return Ok((res.map_into_left_body(), vec![]));
+  };
+
+  // the body is buffered to be validated then sent as is
+  let (req, res) = res.into_parts();
+  let (res, body) = res.into_parts();
+  let body = body::to_bytes(body)
+    .await
+    .map_err(|err| ErrorInternalServerError(err.into()))?;
+  let violations = match serde_json::from_slice::<Value>(&body) {
+    Ok(value) => validator
+      .iter_errors(&value)
+      .map(|error| violation("body", error.instance_path().as_str(), error.to_string()))
+      .collect(),
+    Err(err) => vec![violation("body", "", err.to_string())],
+  };
+  let res = ServiceResponse::new(req, res.set_body(body))
+    .map_into_boxed_body()
+    .map_into_right_body();
+  Ok((res, violations))
+}
+
+fn violation(location: &str, name: &str, reason: String) -> InvalidParam {
+  InvalidParam {
+    name: if name.is_empty() { "/" } else { name }.to_owned(),
+    location: location.to_owned(),
+    reason,
+  }
+}
@@ -90,7 +90,7 @@ async fn created_json_list() {
                 }
               },
               "403": {
-                "description": "Forbidden"
+                "$ref": "#/components/responses/ErrorResponse403"
               }
             },
             "deprecated": false
@@ -98,6 +98,11 @@ async fn created_json_list() {
         }
       },
       "components": {
+        "responses": {
+          "ErrorResponse403": {
+            "description": "Forbidden"
+          }
+        },
         "schemas": {
           "Test": {
             "title": "Test",
@@ -119,10 +124,19 @@ use apistos_rapidoc as _;
 use apistos_redoc as _;
 use apistos_scalar as _;
 use apistos_swagger_ui as _;
+#[cfg(feature = "brotli")]
+use brotli as _;
+use convert_case as _;
+#[cfg(feature = "gzip")]
+use flate2 as _;
 use futures_util as _;
 use garde_actix_web as _;
 use indexmap as _;
+#[cfg(feature = "validation")]
+use jsonschema as _;
 use log as _;
 use md5 as _;
 use once_cell as _;
 use regex as _;
+#[cfg(feature = "yaml")]
+use serde_yaml_ng as _;
@@ -0,0 +1,119 @@
+#![allow(clippy::expect_used)]
+
+use actix_web::App;
+use actix_web::web::Json;
+use apistos::app::OpenApiWrapper;
+use apistos::spec::Spec;
+use apistos::validation::{ExampleViolation, validate_examples};
+use apistos::web::{post, resource, scope};
+use apistos_gen::{ApiComponent, api_operation};
+use apistos_models::info::Info;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema, ApiComponent)]
+#[openapi(example = json!({ "name": "Rex", "age": 3 }))]
+struct Pet {
+  #[schemars(length(min = 1))]
+  name: String,
+  age: u8,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema, ApiComponent)]
+#[schemars(example = "invalid_owner")]
+struct Owner {
+  name: String,
+}
+
+fn invalid_owner() -> serde_json::Value {
+  json!({ "firstname": "John" })
+}
+
+#[api_operation(
+  tag = "pet",
+  request_example(name = "empty_name", value = json!({ "name": "", "age": 3 })),
+  response_example(name = "negative_age", value = json!({ "name": "Rex", "age": -1 }))
+)]
+async fn create_pet(body: Json<Pet>) -> Json<Pet> {
+  body
+}
+
+#[api_operation(tag = "owner")]
+async fn create_owner(body: Json<Owner>) -> Json<Owner> {
+  body
+}
+
+fn spec() -> Spec {
+  Spec {
+    info: Info {
+      title: "A well documented API".to_string(),
+      ..Default::default()
+    },
+    ..Default::default()
+  }
+}
+
+#[test]
+fn invalid_operation_examples() {
+  let open_api = App::new()
+    .document(spec())
+    .service(scope("/pets").service(resource("").route(post().to(create_pet))))
+    .spec();
+
+  let mut violations = validate_examples(&open_api)
+    .into_iter()
+    .map(|ExampleViolation { location, .. }| location)
+    .collect::<Vec<_>>();
+  violations.sort();
+  violations.dedup();
+  assert_eq!(
+    violations,
+    vec![
+      "/paths/~1pets/post/requestBody/content/application~1json/examples/empty_name".to_owned(),
+      "/paths/~1pets/post/responses/200/content/application~1json/examples/negative_age".to_owned(),
+    ]
+  );
+}
+
+#[test]
+fn invalid_component_examples() {
+  let open_api = App::new()
+    .document(spec())
+    .service(scope("/owners").service(resource("").route(post().to(create_owner))))
+    .spec();
+
+  let violations = validate_examples(&open_api);
+  assert!(!violations.is_empty());
+  assert!(
+    violations
+      .iter()
+      .all(|violation| violation.location == "/components/schemas/Owner/examples/0")
+  );
+}
+
+// Imports bellow aim at making clippy happy. Those dependencies are necessary for integration-test.
+use actix_service as _;
+use actix_web_lab as _;
+use apistos_core as _;
+use apistos_plugins as _;
+use apistos_rapidoc as _;
+use apistos_redoc as _;
+use apistos_scalar as _;
+use apistos_swagger_ui as _;
+use assert_json_diff as _;
+#[cfg(feature = "brotli")]
+use brotli as _;
+use convert_case as _;
+#[cfg(feature = "gzip")]
+use flate2 as _;
+use futures_util as _;
+use garde_actix_web as _;
+use indexmap as _;
+use jsonschema as _;
+use log as _;
+use md5 as _;
+use once_cell as _;
+use regex as _;
+#[cfg(feature = "yaml")]
+use serde_yaml_ng as _;
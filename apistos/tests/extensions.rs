@@ -0,0 +1,97 @@
+#![allow(clippy::expect_used)]
+#![allow(clippy::panic)]
+
+use actix_web::App;
+use actix_web::web::{Json, Path};
+use apistos::app::OpenApiWrapper;
+use apistos::spec::Spec;
+use apistos::web::{get, resource, scope};
+use apistos_gen::{ApiComponent, api_operation};
+use apistos_models::info::Info;
+use indexmap::IndexMap;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema, ApiComponent)]
+struct Pet {
+  id: u32,
+}
+
+#[api_operation(tag = "pet")]
+async fn get_pet(_id: Path<u32>) -> Json<Pet> {
+  panic!()
+}
+
+#[api_operation(tag = "pet")]
+async fn list_pets() -> Json<Vec<Pet>> {
+  panic!()
+}
+
+#[test]
+fn extensions() {
+  let spec = Spec {
+    info: Info {
+      title: "A well documented API".to_string(),
+      ..Default::default()
+    },
+    extensions: IndexMap::from_iter(vec![
+      ("x-api-id".to_owned(), json!("pets")),
+      ("api-version".to_owned(), json!("1")),
+    ]),
+    ..Default::default()
+  };
+
+  let open_api = App::new()
+    .document(spec)
+    .service(
+      scope("/pets")
+        .extension("x-internal", json!(true))
+        .extension("internal", json!(true))
+        .service(
+          resource("/{id}")
+            .extension("x-amazon-apigateway-any-method", json!({ "isDefaultRoute": false }))
+            .route(get().to(get_pet)),
+        )
+        .service(resource("").route(get().to(list_pets))),
+    )
+    .spec();
+  let open_api = serde_json::to_value(open_api).expect("Unable to serialize as Json");
+
+  assert_eq!(open_api.get("x-api-id"), Some(&json!("pets")));
+  assert_eq!(open_api.get("api-version"), None);
+  assert_eq!(
+    open_api.pointer("/paths/~1pets~1{id}/x-amazon-apigateway-any-method"),
+    Some(&json!({ "isDefaultRoute": false }))
+  );
+  assert_eq!(open_api.pointer("/paths/~1pets~1{id}/x-internal"), Some(&json!(true)));
+  assert_eq!(open_api.pointer("/paths/~1pets/x-internal"), Some(&json!(true)));
+  assert_eq!(open_api.pointer("/paths/~1pets/internal"), None);
+  assert_eq!(open_api.pointer("/paths/~1pets/x-amazon-apigateway-any-method"), None);
+}
+
+// Imports bellow aim at making clippy happy. Those dependencies are necessary for integration-test.
+use actix_service as _;
+use actix_web_lab as _;
+use apistos_core as _;
+use apistos_plugins as _;
+use apistos_rapidoc as _;
+use apistos_redoc as _;
+use apistos_scalar as _;
+use apistos_swagger_ui as _;
+use assert_json_diff as _;
+#[cfg(feature = "brotli")]
+use brotli as _;
+use convert_case as _;
+#[cfg(feature = "gzip")]
+use flate2 as _;
+use futures_util as _;
+use garde_actix_web as _;
+#[cfg(feature = "validation")]
+use jsonschema as _;
+use log as _;
+use md5 as _;
+use once_cell as _;
+use regex as _;
+#[cfg(feature = "yaml")]
+use serde_yaml_ng as _;
@@ -0,0 +1,155 @@
+#![allow(clippy::panic)]
+
+use actix_web::App;
+use actix_web::web::{Json, Path};
+use apistos::app::OpenApiWrapper;
+use apistos::lint::{LintIssue, lint};
+use apistos::spec::Spec;
+use apistos::web::{get, resource, scope};
+use apistos_gen::{ApiComponent, api_operation};
+use apistos_models::paths::{Parameter, ParameterIn};
+use apistos_models::reference_or::ReferenceOr;
+use apistos_models::tag::Tag;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema, ApiComponent)]
+struct Pet {
+  id: u32,
+}
+
+#[api_operation(tag = "pet", operation_id = "get_pet")]
+async fn get_pet(_id: Path<u32>) -> Json<Pet> {
+  panic!()
+}
+
+#[api_operation(
+  tag = "owner",
+  operation_id = "get_pet",
+  security_scope(name = "api_key", scope = "read:pets")
+)]
+async fn get_owner_pet() -> Json<Pet> {
+  panic!()
+}
+
+#[api_operation(
+  response_header(name = "Location", schema = String, code = 200),
+  response_header(name = "Retry-After", schema = u32, code = 429),
+  link(name = "GetPet", operation_id = "get_pet", code = 429)
+)]
+async fn list_pets() -> Json<Vec<Pet>> {
+  panic!()
+}
+
+#[test]
+fn lint_issues() {
+  let spec = Spec {
+    tags: vec![Tag {
+      name: "pet".to_owned(),
+      ..Default::default()
+    }],
+    ..Default::default()
+  };
+
+  let app = App::new()
+    .document(spec)
+    .service(
+      scope("/pets")
+        .service(resource("/{id}").route(get().to(get_pet)))
+        .service(resource("/{id}").route(get().to(get_pet))),
+    )
+    .service(resource("/owners/{owner_id}/pet").route(get().to(get_owner_pet)));
+  assert_eq!(
+    app.lint(),
+    vec![
+      LintIssue::DuplicatedOperationId {
+        operation_id: "get_pet".to_owned(),
+        operations: vec!["GET /pets/{id}".to_owned(), "GET /owners/{owner_id}/pet".to_owned()],
+      },
+      LintIssue::DuplicatedOperation {
+        operation: "GET /pets/{id}".to_owned(),
+      },
+      LintIssue::UndeclaredTag {
+        tag: "owner".to_owned(),
+        operation: "GET /owners/{owner_id}/pet".to_owned(),
+      },
+      LintIssue::UndeclaredSecurityScheme {
+        name: "api_key".to_owned(),
+        operation: Some("GET /owners/{owner_id}/pet".to_owned()),
+      },
+      LintIssue::MissingPathParameter {
+        parameter: "owner_id".to_owned(),
+        operation: "GET /owners/{owner_id}/pet".to_owned(),
+      },
+    ]
+  );
+
+  let mut open_api = app.spec();
+  let path_item = open_api.paths.paths.entry("/pets/{id}".to_owned()).or_default();
+  path_item.parameters = vec![
+    ReferenceOr::Reference {
+      _ref: "#/components/parameters/page".to_owned(),
+    },
+    ReferenceOr::Object(Parameter {
+      name: "name".to_owned(),
+      _in: ParameterIn::Path,
+      ..Default::default()
+    }),
+  ];
+  open_api.paths.paths.truncate(1);
+  assert_eq!(
+    lint(&open_api),
+    vec![
+      LintIssue::DanglingReference {
+        location: "/paths/~1pets~1{id}/parameters/0/$ref".to_owned(),
+        reference: "#/components/parameters/page".to_owned(),
+      },
+      LintIssue::UnknownPathParameter {
+        parameter: "name".to_owned(),
+        operation: "GET /pets/{id}".to_owned(),
+      },
+    ]
+  );
+}
+
+#[test]
+fn undocumented_response_targets() {
+  let app = App::new()
+    .document(Spec::default())
+    .service(resource("/pets").route(get().to(list_pets)));
+  assert_eq!(
+    app.lint(),
+    vec![LintIssue::UndocumentedResponseTarget {
+      status: 429,
+      operation: "GET /pets".to_owned(),
+    }]
+  );
+}
+
+// Imports bellow aim at making clippy happy. Those dependencies are necessary for integration-test.
+use actix_service as _;
+use actix_web_lab as _;
+use apistos_core as _;
+use apistos_plugins as _;
+use apistos_rapidoc as _;
+use apistos_redoc as _;
+use apistos_scalar as _;
+use apistos_swagger_ui as _;
+use assert_json_diff as _;
+#[cfg(feature = "brotli")]
+use brotli as _;
+use convert_case as _;
+#[cfg(feature = "gzip")]
+use flate2 as _;
+use futures_util as _;
+use garde_actix_web as _;
+use indexmap as _;
+#[cfg(feature = "validation")]
+use jsonschema as _;
+use log as _;
+use md5 as _;
+use once_cell as _;
+use regex as _;
+use serde_json as _;
+#[cfg(feature = "yaml")]
+use serde_yaml_ng as _;
@@ -0,0 +1,163 @@
+#![allow(clippy::panic)]
+
+use actix_web::App;
+use actix_web::http::StatusCode;
+use actix_web::test::{TestRequest, call_service, init_service, read_body};
+use apistos::actix::Negotiated;
+use apistos::app::{BuildConfig, OpenApiWrapper};
+use apistos::spec::Spec;
+use apistos::web::{get, resource};
+use apistos_gen::{ApiComponent, api_operation};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema, ApiComponent)]
+struct Report {
+  total: u32,
+}
+
+#[api_operation(
+  produces = "application/json",
+  produces = "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+  produces(content_type = "text/csv", body = String)
+)]
+async fn get_report() -> Negotiated<Report> {
+  Negotiated::new(Report { total: 42 })
+    .with_json()
+    .with("text/csv", |report: &Report| format!("total\n{}\n", report.total))
+    .with(
+      "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+      |_: &Report| vec![0x50, 0x4b],
+    )
+}
+
+#[api_operation(produces(content_type = "text/csv", body = String))]
+async fn get_csv_report() -> Negotiated<Report> {
+  Negotiated::new(Report { total: 42 }).with("text/csv", |report: &Report| format!("total\n{}\n", report.total))
+}
+
+#[actix_web::test]
+async fn negotiated_responses() {
+  let app = App::new()
+    .document(Spec::default())
+    .service(resource("/report").route(get().to(get_report)))
+    .service(resource("/report.csv").route(get().to(get_csv_report)));
+  let open_api = serde_json::to_value(app.spec()).unwrap_or_default();
+  let app = init_service(app.build_with("/openapi.json", BuildConfig::default())).await;
+
+  let content_types = open_api
+    .pointer("/paths/~1report/get/responses/200/content")
+    .and_then(Value::as_object)
+    .map(|content| content.keys().cloned().collect::<Vec<_>>())
+    .unwrap_or_default();
+  assert_eq!(
+    content_types,
+    vec![
+      "application/json",
+      "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+      "text/csv"
+    ]
+  );
+  assert_eq!(
+    open_api.pointer("/paths/~1report/get/responses/200/content/text~1csv/schema"),
+    Some(&json!({ "title": "String", "type": "string" }))
+  );
+  assert_eq!(
+    open_api.pointer("/paths/~1report/get/responses/406/description"),
+    Some(&json!("Not Acceptable"))
+  );
+
+  let cases = [
+    (
+      "/report",
+      None,
+      StatusCode::OK,
+      Some("application/json"),
+      "{\"total\":42}",
+    ),
+    (
+      "/report",
+      Some("text/csv"),
+      StatusCode::OK,
+      Some("text/csv"),
+      "total\n42\n",
+    ),
+    (
+      "/report",
+      Some("text/*, application/json;q=0.5"),
+      StatusCode::OK,
+      Some("text/csv"),
+      "total\n42\n",
+    ),
+    (
+      "/report",
+      Some("text/csv;q=0, */*"),
+      StatusCode::OK,
+      Some("application/json"),
+      "{\"total\":42}",
+    ),
+    (
+      "/report",
+      Some("application/json;q=0, */*"),
+      StatusCode::OK,
+      Some("text/csv"),
+      "total\n42\n",
+    ),
+    ("/report", Some("application/xml"), StatusCode::NOT_ACCEPTABLE, None, ""),
+    ("/report.csv", None, StatusCode::OK, Some("text/csv"), "total\n42\n"),
+    (
+      "/report.csv",
+      Some("application/json"),
+      StatusCode::NOT_ACCEPTABLE,
+      None,
+      "",
+    ),
+  ];
+  for (uri, accept, status, content_type, expected_body) in cases {
+    let mut req = TestRequest::get().uri(uri);
+    if let Some(accept) = accept {
+      req = req.insert_header(("Accept", accept));
+    }
+    let resp = call_service(&app, req.to_request()).await;
+    assert_eq!(resp.status(), status, "{uri} {accept:?}");
+    assert_eq!(
+      resp
+        .headers()
+        .get("content-type")
+        .and_then(|content_type| content_type.to_str().ok()),
+      content_type,
+      "{uri} {accept:?}"
+    );
+    let body = read_body(resp).await;
+    assert_eq!(body, expected_body.as_bytes(), "{uri} {accept:?}");
+  }
+}
+
+// Imports bellow aim at making clippy happy. Those dependencies are necessary for integration-test.
+use actix_service as _;
+use actix_web_lab as _;
+use apistos_core as _;
+use apistos_models as _;
+use apistos_plugins as _;
+use apistos_rapidoc as _;
+use apistos_redoc as _;
+use apistos_scalar as _;
+use apistos_swagger_ui as _;
+use assert_json_diff as _;
+#[cfg(feature = "brotli")]
+use brotli as _;
+use convert_case as _;
+#[cfg(feature = "gzip")]
+use flate2 as _;
+use futures_util as _;
+use garde_actix_web as _;
+use indexmap as _;
+#[cfg(feature = "validation")]
+use jsonschema as _;
+use log as _;
+use md5 as _;
+use once_cell as _;
+use regex as _;
+#[cfg(feature = "yaml")]
+use serde_yaml_ng as _;
@@ -6,8 +6,8 @@ use actix_web::test::{TestRequest, call_service, init_service, try_read_body_jso
 use actix_web::web::{Json, Path};
 use actix_web::{App, ResponseError};
 use apistos::app::OpenApiWrapper;
-use apistos::spec::Spec;
-use apistos::web::{get, resource, scope};
+use apistos::spec::{OperationIdStrategy, Spec};
+use apistos::web::{get, post, resource, scope};
 use apistos_gen::{ApiComponent, ApiErrorComponent, api_operation};
 use apistos_models::OpenApi;
 use apistos_models::info::Info;
@@ -16,6 +16,7 @@ use apistos_models::tag::Tag;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
+use std::sync::Arc;
 
 #[actix_web::test]
 async fn default_operation_id() {
@@ -136,6 +137,84 @@ async fn default_operation_id() {
   assert_eq!(operation_id3, Some("test3".to_string()))
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema, ApiComponent)]
+struct Pet {
+  id: u32,
+}
+
+#[api_operation(tag = "pet")]
+async fn get_pet(_id: Path<u32>) -> Json<Pet> {
+  panic!()
+}
+
+#[api_operation(tag = "pet")]
+async fn get_pet_owner(_ids: Path<(u32, u32)>) -> Json<Pet> {
+  panic!()
+}
+
+#[api_operation(tag = "pet", operation_id = "create_pet")]
+async fn add_pet(_body: Json<Pet>) -> Json<Pet> {
+  panic!()
+}
+
+fn operation_ids(strategy: OperationIdStrategy) -> Vec<String> {
+  let spec = Spec {
+    operation_id_strategy: strategy,
+    ..Default::default()
+  };
+  let open_api: OpenApi = App::new()
+    .document(spec)
+    .service(
+      scope("/pets")
+        .service(resource("/{id:\\d+}").name("pet").route(get().to(get_pet)))
+        .service(resource("/{petID}/owners/{ownerID}").route(get().to(get_pet_owner)))
+        .service(resource("").route(post().to(add_pet))),
+    )
+    .spec();
+  open_api
+    .paths
+    .paths
+    .values()
+    .flat_map(|path_item| path_item.operations.values())
+    .filter_map(|operation| operation.operation_id.clone())
+    .collect()
+}
+
+#[test]
+fn operation_id_strategies() {
+  assert_eq!(
+    operation_ids(OperationIdStrategy::Hash),
+    vec![
+      "get_pets-8c183247c22811e2f5fb4459eb5c8058",
+      "get_pets-94105108b0d9b62fe455bd61bace62ad",
+      "create_pet"
+    ]
+  );
+  assert_eq!(
+    operation_ids(OperationIdStrategy::HandlerName),
+    vec!["get_pet", "get_pet_owner", "create_pet"]
+  );
+  // operations of unnamed resources fall back to the hash strategy
+  assert_eq!(
+    operation_ids(OperationIdStrategy::ResourceName),
+    vec!["get_pet", "get_pets-94105108b0d9b62fe455bd61bace62ad", "create_pet"]
+  );
+  assert_eq!(
+    operation_ids(OperationIdStrategy::CamelCase),
+    vec!["getPetsById", "getPetsByPetIDOwnersByOwnerID", "create_pet"]
+  );
+  assert_eq!(
+    operation_ids(OperationIdStrategy::SnakeCase),
+    vec!["get_pets_by_id", "get_pets_by_pet_id_owners_by_owner_id", "create_pet"]
+  );
+  assert_eq!(
+    operation_ids(OperationIdStrategy::Custom(Arc::new(
+      |path: &str, operation_type: OperationType, _operation: &_| format!("{operation_type:?}{path}")
+    ))),
+    vec!["Get/pets/{id}", "Get/pets/{petID}/owners/{ownerID}", "create_pet"]
+  );
+}
+
 // Imports bellow aim at making clippy happy. Those dependencies are necessary for integration-test.
 use actix_service as _;
 use actix_web_lab as _;
@@ -146,11 +225,20 @@ use apistos_redoc as _;
 use apistos_scalar as _;
 use apistos_swagger_ui as _;
 use assert_json_diff as _;
+#[cfg(feature = "brotli")]
+use brotli as _;
+use convert_case as _;
+#[cfg(feature = "gzip")]
+use flate2 as _;
 use futures_util as _;
 use garde_actix_web as _;
 use indexmap as _;
+#[cfg(feature = "validation")]
+use jsonschema as _;
 use log as _;
 use md5 as _;
 use once_cell as _;
 use regex as _;
 use serde_json as _;
+#[cfg(feature = "yaml")]
+use serde_yaml_ng as _;
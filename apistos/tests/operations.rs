@@ -347,9 +347,16 @@ use apistos_redoc as _;
 use apistos_scalar as _;
 use apistos_swagger_ui as _;
 use assert_json_diff as _;
+#[cfg(feature = "brotli")]
+use brotli as _;
+use convert_case as _;
+#[cfg(feature = "gzip")]
+use flate2 as _;
 use futures_util as _;
 use garde_actix_web as _;
 use indexmap as _;
+#[cfg(feature = "validation")]
+use jsonschema as _;
 use log as _;
 use md5 as _;
 use once_cell as _;
@@ -357,3 +364,5 @@ use regex as _;
 use schemars as _;
 use serde as _;
 use serde_json as _;
+#[cfg(feature = "yaml")]
+use serde_yaml_ng as _;
@@ -0,0 +1,165 @@
+#![allow(clippy::expect_used)]
+
+use actix_web::http::StatusCode;
+use actix_web::http::header::{self, HeaderValue};
+use actix_web::test::{TestRequest, call_service, init_service, read_body};
+use actix_web::web::{Json, Path, Query};
+use actix_web::{App, Error};
+use apistos::app::OpenApiWrapper;
+use apistos::spec::Spec;
+use apistos::web::{post, resource, scope};
+use apistos_gen::{ApiComponent, api_operation};
+use apistos_models::info::Info;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema, ApiComponent)]
+struct NewPet {
+  name: String,
+  age: u8,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema, ApiComponent)]
+struct Pagination {
+  limit: Option<u8>,
+}
+
+#[api_operation(tag = "pet")]
+async fn create_pet(
+  pet_id: Path<u32>,
+  _pagination: Query<Pagination>,
+  body: Json<NewPet>,
+) -> Result<Json<NewPet>, Error> {
+  assert_eq!(pet_id.into_inner(), 42);
+  Ok(body)
+}
+
+async fn call(req: TestRequest) -> (StatusCode, Option<HeaderValue>, Value) {
+  let spec = Spec {
+    info: Info {
+      title: "A well documented API".to_string(),
+      ..Default::default()
+    },
+    ..Default::default()
+  };
+  let app = App::new()
+    .document(spec)
+    .validate_requests()
+    .service(scope("/pets").service(resource("/{pet_id}").route(post().to(create_pet))))
+    .build("/openapi.json");
+  let app = init_service(app).await;
+
+  let resp = call_service(&app, req.to_request()).await;
+  let status = resp.status();
+  let content_type = resp.headers().get(header::CONTENT_TYPE).cloned();
+  let body = read_body(resp).await;
+  (status, content_type, serde_json::from_slice(&body).unwrap_or_default())
+}
+
+#[actix_web::test]
+async fn valid_request_forwarded() {
+  let (status, _, body) = call(
+    TestRequest::post()
+      .uri("/pets/42?limit=10")
+      .set_json(json!({ "name": "Rex", "age": 3 })),
+  )
+  .await;
+
+  assert_eq!(status, StatusCode::OK);
+  assert_eq!(body, json!({ "name": "Rex", "age": 3 }));
+}
+
+#[actix_web::test]
+async fn invalid_parameters_rejected() {
+  let (status, content_type, body) = call(
+    TestRequest::post()
+      .uri("/pets/rex?limit=-1")
+      .set_json(json!({ "name": "Rex", "age": 3 })),
+  )
+  .await;
+
+  assert_eq!(status, StatusCode::BAD_REQUEST);
+  assert_eq!(content_type, Some(HeaderValue::from_static("application/problem+json")));
+  assert_eq!(body.get("status"), Some(&json!(400)));
+  assert_eq!(body.get("instance"), Some(&json!("/pets/rex")));
+  let invalid_params = body
+    .get("invalid-params")
+    .and_then(Value::as_array)
+    .expect("Missing invalid params");
+  let locations = invalid_params
+    .iter()
+    .filter_map(|invalid_param| Some((invalid_param.get("name")?.as_str()?, invalid_param.get("in")?.as_str()?)))
+    .collect::<Vec<_>>();
+  assert_eq!(locations, vec![("pet_id", "path"), ("limit", "query")]);
+}
+
+#[actix_web::test]
+async fn invalid_body_rejected() {
+  let (status, _, body) = call(
+    TestRequest::post()
+      .uri("/pets/42")
+      .set_json(json!({ "name": "Rex", "age": -3 })),
+  )
+  .await;
+
+  assert_eq!(status, StatusCode::BAD_REQUEST);
+  assert_eq!(
+    body.pointer("/invalid-params/0/name").and_then(Value::as_str),
+    Some("/age")
+  );
+  assert_eq!(
+    body.pointer("/invalid-params/0/in").and_then(Value::as_str),
+    Some("body")
+  );
+}
+
+#[actix_web::test]
+async fn unsupported_content_type_rejected() {
+  let (status, content_type, body) = call(
+    TestRequest::post()
+      .uri("/pets/42")
+      .insert_header((header::CONTENT_TYPE, "text/plain"))
+      .set_payload("Rex"),
+  )
+  .await;
+
+  assert_eq!(status, StatusCode::UNSUPPORTED_MEDIA_TYPE);
+  assert_eq!(content_type, Some(HeaderValue::from_static("application/problem+json")));
+  assert_eq!(body.get("status"), Some(&json!(415)));
+}
+
+#[actix_web::test]
+async fn undocumented_route_forwarded() {
+  let (status, _, _) = call(TestRequest::get().uri("/openapi.json")).await;
+  assert_eq!(status, StatusCode::OK);
+
+  let (status, _, _) = call(TestRequest::get().uri("/unknown")).await;
+  assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+// Imports bellow aim at making clippy happy. Those dependencies are necessary for integration-test.
+use actix_service as _;
+use actix_web_lab as _;
+use apistos_core as _;
+use apistos_plugins as _;
+use apistos_rapidoc as _;
+use apistos_redoc as _;
+use apistos_scalar as _;
+use apistos_swagger_ui as _;
+use assert_json_diff as _;
+#[cfg(feature = "brotli")]
+use brotli as _;
+use convert_case as _;
+#[cfg(feature = "gzip")]
+use flate2 as _;
+use futures_util as _;
+use garde_actix_web as _;
+use indexmap as _;
+use jsonschema as _;
+use log as _;
+use md5 as _;
+use once_cell as _;
+use regex as _;
+#[cfg(feature = "yaml")]
+use serde_yaml_ng as _;
@@ -0,0 +1,159 @@
+#![allow(clippy::expect_used)]
+
+use actix_web::http::StatusCode;
+use actix_web::test::{TestRequest, call_service, init_service, read_body};
+use actix_web::web::Json;
+use actix_web::{App, Either, Error, HttpResponse};
+use apistos::actix::Negotiated;
+use apistos::app::OpenApiWrapper;
+use apistos::spec::Spec;
+use apistos::validation::OnViolation;
+use apistos::web::{get, resource, scope};
+use apistos_gen::{ApiComponent, api_operation};
+use apistos_models::info::Info;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema, ApiComponent)]
+struct Pet {
+  #[schemars(length(min = 1))]
+  name: String,
+}
+
+#[api_operation(tag = "pet")]
+async fn valid_pet() -> Result<Json<Pet>, Error> {
+  Ok(Json(Pet { name: "Rex".to_owned() }))
+}
+
+#[api_operation(tag = "pet")]
+async fn invalid_pet() -> Result<Json<Pet>, Error> {
+  Ok(Json(Pet { name: String::new() }))
+}
+
+#[api_operation(tag = "pet")]
+async fn missing_pet() -> Result<Json<Pet>, Error> {
+  Err(actix_web::error::ErrorNotFound("Missing pet"))
+}
+
+#[api_operation(tag = "pet")]
+async fn plain_pet() -> Either<Json<Pet>, HttpResponse> {
+  Either::Right(HttpResponse::Ok().content_type("text/plain").body("Rex"))
+}
+
+#[api_operation(tag = "pet", produces(content_type = "text/csv; charset=utf-8", body = String))]
+async fn csv_pet() -> Negotiated<Pet> {
+  Negotiated::new(Pet { name: "Rex".to_owned() }).with("text/csv", |pet| pet.name.clone())
+}
+
+#[api_operation(tag = "pet", produces(content_type = "text/csv", body = String))]
+async fn text_pet() -> Negotiated<Pet> {
+  Negotiated::new(Pet { name: "Rex".to_owned() }).with("text/plain", |pet| pet.name.clone())
+}
+
+async fn call(uri: &str, on_violation: OnViolation) -> (StatusCode, Value) {
+  let spec = Spec {
+    info: Info {
+      title: "A well documented API".to_string(),
+      ..Default::default()
+    },
+    ..Default::default()
+  };
+  let app = App::new()
+    .document(spec)
+    .validate_responses(on_violation)
+    .service(
+      scope("/pets")
+        .service(resource("/valid").route(get().to(valid_pet)))
+        .service(resource("/invalid").route(get().to(invalid_pet)))
+        .service(resource("/missing").route(get().to(missing_pet)))
+        .service(resource("/plain").route(get().to(plain_pet)))
+        .service(resource("/csv").route(get().to(csv_pet)))
+        .service(resource("/text").route(get().to(text_pet))),
+    )
+    .build("/openapi.json");
+  let app = init_service(app).await;
+
+  let resp = call_service(&app, TestRequest::get().uri(uri).to_request()).await;
+  let status = resp.status();
+  let body = read_body(resp).await;
+  (status, serde_json::from_slice(&body).unwrap_or_default())
+}
+
+fn violation(body: &Value) -> Option<(&str, &str)> {
+  Some((
+    body.pointer("/invalid-params/0/in")?.as_str()?,
+    body.pointer("/invalid-params/0/name")?.as_str()?,
+  ))
+}
+
+#[actix_web::test]
+async fn valid_response_forwarded() {
+  let (status, body) = call("/pets/valid", OnViolation::Fail).await;
+  assert_eq!(status, StatusCode::OK);
+  assert_eq!(body, json!({ "name": "Rex" }));
+}
+
+#[actix_web::test]
+async fn violations_logged() {
+  let (status, body) = call("/pets/invalid", OnViolation::Log).await;
+  assert_eq!(status, StatusCode::OK);
+  assert_eq!(body, json!({ "name": "" }));
+}
+
+#[actix_web::test]
+async fn schema_violation_failed() {
+  let (status, body) = call("/pets/invalid", OnViolation::Fail).await;
+  assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+  assert_eq!(violation(&body), Some(("body", "/name")));
+}
+
+#[actix_web::test]
+async fn undocumented_status_failed() {
+  let (status, body) = call("/pets/missing", OnViolation::Fail).await;
+  assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+  assert_eq!(violation(&body), Some(("status", "404")));
+}
+
+#[actix_web::test]
+async fn undocumented_content_type_failed() {
+  let (status, body) = call("/pets/plain", OnViolation::Fail).await;
+  assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+  assert_eq!(violation(&body), Some(("header", "content-type")));
+}
+
+#[actix_web::test]
+async fn negotiated_representations_checked() {
+  let (status, _) = call("/pets/csv", OnViolation::Fail).await;
+  assert_eq!(status, StatusCode::OK);
+
+  let (status, body) = call("/pets/text", OnViolation::Fail).await;
+  assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+  assert_eq!(violation(&body), Some(("header", "content-type")));
+}
+
+// Imports bellow aim at making clippy happy. Those dependencies are necessary for integration-test.
+use actix_service as _;
+use actix_web_lab as _;
+use apistos_core as _;
+use apistos_plugins as _;
+use apistos_rapidoc as _;
+use apistos_redoc as _;
+use apistos_scalar as _;
+use apistos_swagger_ui as _;
+use assert_json_diff as _;
+#[cfg(feature = "brotli")]
+use brotli as _;
+use convert_case as _;
+#[cfg(feature = "gzip")]
+use flate2 as _;
+use futures_util as _;
+use garde_actix_web as _;
+use indexmap as _;
+use jsonschema as _;
+use log as _;
+use md5 as _;
+use once_cell as _;
+use regex as _;
+#[cfg(feature = "yaml")]
+use serde_yaml_ng as _;
@@ -0,0 +1,120 @@
+#![allow(clippy::panic)]
+
+use actix_web::App;
+use actix_web::web::{Json, Path};
+use apistos::app::OpenApiWrapper;
+use apistos::spec::Spec;
+use apistos::web::{get, post, resource, scope};
+use apistos_gen::{ApiComponent, api_operation};
+use apistos_models::info::Info;
+use apistos_models::server::Server;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema, ApiComponent)]
+struct Pet {
+  id: u32,
+}
+
+#[api_operation(tag = "pet")]
+async fn get_pet(_id: Path<u32>) -> Json<Pet> {
+  panic!()
+}
+
+#[api_operation(
+  tag = "pet",
+  server(url = "https://upload.pets.com", description = "Upload host"),
+  external_docs(url = "https://runbooks.pets.com/upload", description = "Upload runbook")
+)]
+async fn upload_pet_picture(_id: Path<u32>) -> Json<Pet> {
+  panic!()
+}
+
+fn server(url: &str) -> Server {
+  Server {
+    url: url.to_owned(),
+    ..Default::default()
+  }
+}
+
+#[test]
+fn servers() {
+  let spec = Spec {
+    info: Info {
+      title: "A well documented API".to_string(),
+      ..Default::default()
+    },
+    servers: vec![server("https://api.pets.com")],
+    ..Default::default()
+  };
+
+  let open_api = App::new()
+    .document(spec)
+    .service(
+      scope("/pets")
+        .server(server("https://eu.pets.com"))
+        .service(resource("/{id}").route(get().to(get_pet)))
+        .service(
+          resource("/{id}/picture")
+            .server(server("https://cdn.pets.com"))
+            .route(post().to(upload_pet_picture)),
+        ),
+    )
+    .spec();
+  let open_api = serde_json::to_value(open_api).unwrap_or_default();
+
+  let urls = |pointer: &str| {
+    open_api
+      .pointer(pointer)
+      .and_then(Value::as_array)
+      .map(|servers| {
+        servers
+          .iter()
+          .filter_map(|server| server.get("url").and_then(Value::as_str))
+          .collect::<Vec<_>>()
+      })
+      .unwrap_or_default()
+  };
+  assert_eq!(urls("/servers"), vec!["https://api.pets.com"]);
+  assert_eq!(urls("/paths/~1pets~1{id}/servers"), vec!["https://eu.pets.com"]);
+  assert_eq!(
+    urls("/paths/~1pets~1{id}~1picture/servers"),
+    vec!["https://cdn.pets.com", "https://eu.pets.com"]
+  );
+  assert_eq!(
+    open_api.pointer("/paths/~1pets~1{id}~1picture/post/servers"),
+    Some(&json!([{ "url": "https://upload.pets.com", "description": "Upload host" }]))
+  );
+  assert_eq!(
+    open_api.pointer("/paths/~1pets~1{id}~1picture/post/externalDocs"),
+    Some(&json!({ "url": "https://runbooks.pets.com/upload", "description": "Upload runbook" }))
+  );
+}
+
+// Imports bellow aim at making clippy happy. Those dependencies are necessary for integration-test.
+use actix_service as _;
+use actix_web_lab as _;
+use apistos_core as _;
+use apistos_plugins as _;
+use apistos_rapidoc as _;
+use apistos_redoc as _;
+use apistos_scalar as _;
+use apistos_swagger_ui as _;
+use assert_json_diff as _;
+#[cfg(feature = "brotli")]
+use brotli as _;
+use convert_case as _;
+#[cfg(feature = "gzip")]
+use flate2 as _;
+use futures_util as _;
+use garde_actix_web as _;
+use indexmap as _;
+#[cfg(feature = "validation")]
+use jsonschema as _;
+use log as _;
+use md5 as _;
+use once_cell as _;
+use regex as _;
+#[cfg(feature = "yaml")]
+use serde_yaml_ng as _;
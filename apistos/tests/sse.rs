@@ -0,0 +1,106 @@
+#![allow(clippy::panic)]
+
+use std::convert::Infallible;
+
+use actix_web::App;
+use actix_web::test::{TestRequest, call_service, init_service, read_body};
+use actix_web_lab::sse::{Data, Event, Sse};
+use apistos::actix::{ApiSseEvent, TypedSse};
+use apistos::app::{BuildConfig, OpenApiWrapper};
+use apistos::spec::Spec;
+use apistos::web::{get, resource};
+use apistos_gen::{ApiComponent, api_operation};
+use assert_json_diff::assert_json_eq;
+use futures_util::stream::{Iter, iter};
+use schemars::JsonSchema;
+use serde::Serialize;
+use serde_json::json;
+
+#[derive(Serialize, Debug, Clone, JsonSchema, ApiComponent, ApiSseEvent)]
+#[openapi_sse_event(event = "created", event = "updated")]
+struct PetUpdate {
+  id: u32,
+  name: String,
+}
+
+type PetUpdates = Iter<std::vec::IntoIter<Result<Event, Infallible>>>;
+
+#[api_operation(summary = "Stream pet updates")]
+async fn pet_updates() -> TypedSse<PetUpdate, PetUpdates> {
+  let update = PetUpdate {
+    id: 1,
+    name: "Rex".to_string(),
+  };
+  let event = Data::new_json(update).map(|data| Event::Data(data.event("created")));
+  Sse::from_stream(iter(event.into_iter().map(Ok).collect::<Vec<_>>())).into()
+}
+
+#[actix_web::test]
+async fn sse_responses() {
+  let app = App::new()
+    .document(Spec::default())
+    .service(resource("/pets/updates").route(get().to(pet_updates)));
+  let open_api = serde_json::to_value(app.spec()).unwrap_or_default();
+  let app = init_service(app.build_with("/openapi.json", BuildConfig::default())).await;
+
+  assert_json_eq!(
+    open_api
+      .pointer("/paths/~1pets~1updates/get/responses")
+      .cloned()
+      .unwrap_or_default(),
+    json!({
+      "200": {
+        "description": "",
+        "content": {
+          "text/event-stream": {
+            "schema": {
+              "$ref": "#/components/schemas/PetUpdate"
+            },
+            "x-event-names": ["created", "updated"]
+          }
+        }
+      }
+    })
+  );
+  assert!(
+    open_api
+      .pointer("/components/schemas/PetUpdate")
+      .is_some_and(serde_json::Value::is_object)
+  );
+
+  let response = call_service(&app, TestRequest::get().uri("/pets/updates").to_request()).await;
+  assert_eq!(
+    response
+      .headers()
+      .get("content-type")
+      .and_then(|value| value.to_str().ok()),
+    Some("text/event-stream")
+  );
+  let body = read_body(response).await;
+  assert_eq!(body, "event: created\ndata: {\"id\":1,\"name\":\"Rex\"}\n\n".as_bytes());
+}
+
+// Imports bellow aim at making clippy happy. Those dependencies are necessary for integration-test.
+use actix_service as _;
+use apistos_core as _;
+use apistos_models as _;
+use apistos_plugins as _;
+use apistos_rapidoc as _;
+use apistos_redoc as _;
+use apistos_scalar as _;
+use apistos_swagger_ui as _;
+#[cfg(feature = "brotli")]
+use brotli as _;
+use convert_case as _;
+#[cfg(feature = "gzip")]
+use flate2 as _;
+use garde_actix_web as _;
+use indexmap as _;
+#[cfg(feature = "validation")]
+use jsonschema as _;
+use log as _;
+use md5 as _;
+use once_cell as _;
+use regex as _;
+#[cfg(feature = "yaml")]
+use serde_yaml_ng as _;
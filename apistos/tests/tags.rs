@@ -6,15 +6,16 @@ use actix_web::test::{TestRequest, call_service, init_service, try_read_body_jso
 use actix_web::web::{Json, Path};
 use actix_web::{App, ResponseError};
 use apistos::app::OpenApiWrapper;
-use apistos::spec::Spec;
-use apistos::web::{get, resource, tagged_resource, tagged_scope};
+use apistos::spec::{Spec, TagGroup};
+use apistos::web::{get, resource, resource_with_tags, scope_with_tags, tagged_resource, tagged_scope};
 use apistos_gen::{ApiComponent, ApiErrorComponent, api_operation};
 use apistos_models::OpenApi;
 use apistos_models::info::Info;
-use apistos_models::paths::OperationType;
+use apistos_models::paths::{ExternalDocumentation, OperationType};
 use apistos_models::tag::Tag;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use serde_json::json;
 use std::fmt::{Display, Formatter};
 
 #[actix_web::test]
@@ -140,6 +141,86 @@ async fn tags() {
   assert_eq!(tags, vec!["A super tag"]);
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema, ApiComponent)]
+struct Pet {
+  id: u32,
+}
+
+#[api_operation]
+async fn get_pet(_id: Path<u32>) -> Json<Pet> {
+  panic!()
+}
+
+#[test]
+fn tag_groups() {
+  let spec = Spec {
+    tags: vec![Tag::from("store")],
+    tag_groups: vec![
+      TagGroup::new("Animals", vec!["pet", "photo"]),
+      TagGroup::new("Shop", vec!["store"]),
+    ],
+    extensions: std::iter::once((
+      "x-tagGroups".to_owned(),
+      json!([{ "name": "Shop", "tags": ["store", "order"] }]),
+    ))
+    .collect(),
+    ..Default::default()
+  };
+
+  let open_api = App::new()
+    .document(spec)
+    .service(
+      scope_with_tags(
+        "/pets",
+        vec![Tag {
+          name: "pet".to_owned(),
+          description: Some("Everything about pets".to_owned()),
+          external_docs: Some(ExternalDocumentation {
+            url: "https://pets.com/docs".to_owned(),
+            ..Default::default()
+          }),
+          ..Default::default()
+        }],
+      )
+      .service(resource("/{id}").route(get().to(get_pet)))
+      .service(resource_with_tags("/{id}/photo", vec![Tag::from("pet"), Tag::from("photo")]).route(get().to(get_pet))),
+    )
+    .service(
+      resource_with_tags(
+        "/stores/{id}",
+        vec![Tag {
+          name: "store".to_owned(),
+          description: Some("Pet stores".to_owned()),
+          ..Default::default()
+        }],
+      )
+      .route(get().to(get_pet)),
+    )
+    .spec();
+  let open_api = serde_json::to_value(open_api).expect("Unable to serialize spec");
+
+  assert_eq!(
+    open_api.get("tags"),
+    Some(&json!([
+      { "name": "store", "description": "Pet stores" },
+      { "name": "pet", "description": "Everything about pets", "externalDocs": { "url": "https://pets.com/docs" } },
+      { "name": "photo" }
+    ]))
+  );
+  // groups of the `x-tagGroups` extension take precedence over tag groups with the same name
+  assert_eq!(
+    open_api.get("x-tagGroups"),
+    Some(&json!([
+      { "name": "Shop", "tags": ["store", "order"] },
+      { "name": "Animals", "tags": ["pet", "photo"] }
+    ]))
+  );
+  assert_eq!(
+    open_api.pointer("/paths/~1stores~1{id}/get/tags"),
+    Some(&json!(["store"]))
+  );
+}
+
 // Imports bellow aim at making clippy happy. Those dependencies are necessary for integration-test.
 use actix_service as _;
 use actix_web_lab as _;
@@ -150,11 +231,19 @@ use apistos_redoc as _;
 use apistos_scalar as _;
 use apistos_swagger_ui as _;
 use assert_json_diff as _;
+#[cfg(feature = "brotli")]
+use brotli as _;
+use convert_case as _;
+#[cfg(feature = "gzip")]
+use flate2 as _;
 use futures_util as _;
 use garde_actix_web as _;
 use indexmap as _;
+#[cfg(feature = "validation")]
+use jsonschema as _;
 use log as _;
 use md5 as _;
 use once_cell as _;
 use regex as _;
-use serde_json as _;
+#[cfg(feature = "yaml")]
+use serde_yaml_ng as _;